@@ -0,0 +1,79 @@
+//! Generates a test for every example found in the days' `examples` directories.
+
+use std::{fmt::Write, path::{Path, PathBuf}};
+
+fn main() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let workspace_dir = manifest_dir.parent().unwrap();
+    // New days are added as workspace members, new years come with them
+    println!("cargo:rerun-if-changed={}", workspace_dir.join("Cargo.toml").display());
+
+    // Days are registered as dependencies, the ones that aren't yet (like a day that was
+    // just created) get their tests ignored instead of failing
+    let manifest_path = manifest_dir.join("Cargo.toml");
+    println!("cargo:rerun-if-changed={}", manifest_path.display());
+    let manifest: toml::Table = std::fs::read_to_string(&manifest_path).unwrap().parse().unwrap();
+    let registered = manifest["dependencies"].as_table().unwrap();
+
+    // Days live in `yYYYY/day_XX`
+    let mut day_dirs = vec![];
+    for year_dir in subdirs(workspace_dir) {
+        let Some(year) = number_after(&year_dir, "y") else {
+            continue;
+        };
+        println!("cargo:rerun-if-changed={}", year_dir.display());
+        for day_dir in subdirs(&year_dir) {
+            if let Some(day) = number_after(&day_dir, "day_") {
+                day_dirs.push((year, day as u8, day_dir));
+            }
+        }
+    }
+    day_dirs.sort();
+
+    let mut tests = String::new();
+    for (year, day, day_dir) in day_dirs {
+        let crate_name = format!("y{year}_day_{:02}", day);
+
+        let examples_dir = day_dir.join("examples");
+        if !examples_dir.is_dir() {
+            continue;
+        }
+        println!("cargo:rerun-if-changed={}", examples_dir.display());
+
+        let mut sidecars: Vec<_> = std::fs::read_dir(&examples_dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|p| p.extension().is_some_and(|ext| ext == "toml"))
+            .collect();
+        sidecars.sort();
+
+        for sidecar in sidecars {
+            let example_name = sidecar.file_stem().unwrap().to_string_lossy()
+                .replace(|ch: char| !ch.is_ascii_alphanumeric(), "_");
+            writeln!(tests, "#[test]").unwrap();
+            if !registered.contains_key(&crate_name) {
+                writeln!(tests, "#[ignore = \"{crate_name} isn't registered in aoc yet\"]").unwrap();
+            }
+            writeln!(tests, "fn {crate_name}_{example_name}() {{").unwrap();
+            writeln!(tests, "    check_example({year}, {day}, {:?});", sidecar.display().to_string()).unwrap();
+            writeln!(tests, "}}").unwrap();
+            writeln!(tests).unwrap();
+        }
+    }
+
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    std::fs::write(Path::new(&out_dir).join("examples.rs"), tests).unwrap();
+}
+
+fn subdirs(dir: &Path) -> Vec<PathBuf> {
+    std::fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|p| p.is_dir())
+        .collect()
+}
+
+/// Parses the directory name after `prefix`, like the `2024` of `y2024`.
+fn number_after(dir: &Path, prefix: &str) -> Option<u64> {
+    dir.file_name()?.to_str()?.strip_prefix(prefix)?.parse().ok()
+}
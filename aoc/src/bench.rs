@@ -0,0 +1,167 @@
+use std::{collections::BTreeMap, path::{Path, PathBuf}, time::{Duration, Instant}};
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use xmas::solution::{DynSolution, RunTimes};
+
+/// Relative change under which a difference against the baseline is considered noise.
+const NOISE_THRESHOLD: f64 = 0.05;
+
+#[derive(Debug, Clone)]
+pub struct BenchOptions {
+    /// Amount of runs to measure, after a warm up run
+    pub samples: usize,
+    /// Stops taking samples after this time, even if `samples` wasn't reached
+    pub max_time: Duration,
+}
+
+/// Statistics of a single step (parse or a part) over every sample.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub samples: usize,
+    pub mean_ns: u64,
+    pub median_ns: u64,
+    pub min_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut nanos: Vec<u64> = samples.iter()
+            .map(|d| d.as_nanos() as u64)
+            .collect();
+        nanos.sort();
+
+        let len = nanos.len();
+        let median_ns = if len.is_multiple_of(2) {
+            (nanos[len / 2 - 1] + nanos[len / 2]) / 2
+        } else {
+            nanos[len / 2]
+        };
+        Self {
+            samples: len,
+            mean_ns: nanos.iter().sum::<u64>() / len as u64,
+            median_ns,
+            min_ns: nanos[0],
+            max_ns: nanos[len - 1],
+        }
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+
+    /// Relative change of the median compared to `baseline`, positive means slower.
+    pub fn change_from(&self, baseline: &Stats) -> f64 {
+        (self.median_ns as f64 - baseline.median_ns as f64) / baseline.median_ns.max(1) as f64
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct DayBench {
+    pub parse: Stats,
+    pub part_1: Stats,
+    pub part_2: Stats,
+}
+
+impl DayBench {
+    fn steps(&self) -> [(&'static str, &Stats); 3] {
+        [("Parse", &self.parse), ("Part 1", &self.part_1), ("Part 2", &self.part_2)]
+    }
+}
+
+/// Results of a whole benchmark run, stored as JSON to compare later runs against.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub days: BTreeMap<u8, DayBench>,
+}
+
+impl Baseline {
+    pub fn path(year: u64, name: &str) -> PathBuf {
+        PathBuf::from("target").join("aoc-bench").join(year.to_string()).join(format!("{name}.json"))
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Error reading baseline {}.", path.display()))?;
+        Ok(serde_json::from_str(&content)?)
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Error writing baseline {}.", path.display()))
+    }
+}
+
+pub fn bench_day(solution: &dyn DynSolution, input: &str, options: &BenchOptions) -> anyhow::Result<DayBench> {
+    // Warm up
+    solution.run_timed(input)?;
+
+    let mut runs: Vec<RunTimes> = vec![];
+    let start = Instant::now();
+    while runs.len() < options.samples.max(1) {
+        runs.push(solution.run_timed(input)?);
+        if start.elapsed() > options.max_time {
+            break;
+        }
+    }
+
+    let stats_of = |step: fn(&RunTimes) -> Duration| {
+        Stats::from_samples(&runs.iter().map(step).collect::<Vec<_>>())
+    };
+    Ok(DayBench {
+        parse: stats_of(|r| r.parse),
+        part_1: stats_of(|r| r.part_1),
+        part_2: stats_of(|r| r.part_2),
+    })
+}
+
+pub fn print_report(day: u8, bench: &DayBench, baseline: Option<&DayBench>) {
+    println!("Day {day}:");
+    for (i, (name, stats)) in bench.steps().into_iter().enumerate() {
+        let comparison = match baseline {
+            Some(baseline) => {
+                let (_, baseline_stats) = baseline.steps()[i];
+                let change = stats.change_from(baseline_stats);
+                let verdict = if change > NOISE_THRESHOLD {
+                    "regressed"
+                } else if change < -NOISE_THRESHOLD {
+                    "improved"
+                } else {
+                    "no change"
+                };
+                format!(" | baseline {:>12?} {:>+7.1}% {verdict}", baseline_stats.median(), change * 100.0)
+            }
+            None => String::new(),
+        };
+        println!("  {name}: {:>12?} (min {:?}, max {:?}, {} samples){comparison}",
+            stats.median(),
+            Duration::from_nanos(stats.min_ns),
+            Duration::from_nanos(stats.max_ns),
+            stats.samples);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calculates_stats() {
+        let samples = [3, 1, 4, 2].map(Duration::from_nanos);
+
+        let stats = Stats::from_samples(&samples);
+
+        assert_eq!(stats, Stats { samples: 4, mean_ns: 2, median_ns: 2, min_ns: 1, max_ns: 4 });
+    }
+
+    #[test]
+    fn change_is_relative_to_baseline_median() {
+        let baseline = Stats::from_samples(&[Duration::from_nanos(100)]);
+        let current = Stats::from_samples(&[Duration::from_nanos(150)]);
+
+        assert_eq!(current.change_from(&baseline), 0.5);
+    }
+}
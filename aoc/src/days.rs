@@ -0,0 +1,45 @@
+use std::path::PathBuf;
+use xmas::solution::DynSolution;
+
+/// Years with at least one registered solution, oldest first.
+pub const YEARS: &[u64] = &[2024];
+
+/// Returns the solution registered for the given day, if any.
+pub fn get_solution(year: u64, day: u8) -> Option<&'static dyn DynSolution> {
+    Some(match (year, day) {
+        (2024, 1) => &y2024_day_01::Puzzle,
+        (2024, 2) => &y2024_day_02::Puzzle,
+        (2024, 3) => &y2024_day_03::Puzzle,
+        (2024, 4) => &y2024_day_04::Puzzle,
+        (2024, 5) => &y2024_day_05::Puzzle,
+        (2024, 6) => &y2024_day_06::Puzzle,
+        (2024, 7) => &y2024_day_07::Puzzle,
+        (2024, 8) => &y2024_day_08::Puzzle,
+        (2024, 9) => &y2024_day_09::Puzzle,
+        (2024, 10) => &y2024_day_10::Puzzle,
+        (2024, 11) => &y2024_day_11::Puzzle,
+        (2024, 12) => &y2024_day_12::Puzzle,
+        (2024, 13) => &y2024_day_13::Puzzle,
+        (2024, 14) => &y2024_day_14::Puzzle,
+        (2024, 15) => &y2024_day_15::Puzzle,
+        (2024, 16) => &y2024_day_16::Puzzle,
+        (2024, 17) => &y2024_day_17::Puzzle,
+        (2024, 18) => &y2024_day_18::Puzzle,
+        (2024, 19) => &y2024_day_19::Puzzle,
+        (2024, 20) => &y2024_day_20::Puzzle,
+        _ => return None,
+    })
+}
+
+pub fn available_days(year: u64) -> impl Iterator<Item = u8> {
+    (1..=25).filter(move |&day| get_solution(year, day).is_some())
+}
+
+pub fn latest_year() -> u64 {
+    *YEARS.last().unwrap()
+}
+
+/// Directory of a day's crate, relative to the workspace root.
+pub fn day_dir(year: u64, day: u8) -> PathBuf {
+    PathBuf::from(format!("y{year}")).join(format!("day_{:02}", day))
+}
@@ -0,0 +1,214 @@
+use std::{path::{Path, PathBuf}, time::Duration};
+use anyhow::{self, Context};
+use aoc::{bench::{self, Baseline, BenchOptions}, days, submit::{self, Ledger, Outcome}};
+use create_new_day::input::{self, HttpInput};
+use clap::{Parser, Subcommand};
+use xmas::{answers::{Answers, Verdict}, display_result, solution::Part};
+
+#[derive(Parser, Debug)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Runs one or all of the days' solutions
+    Run {
+        /// Puzzle year, defaults to the latest year with solutions
+        #[arg(long, value_parser = clap::value_parser!(u64).range(2015..))]
+        year: Option<u64>,
+        /// Day to run, runs every available day if omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Part to run, runs both parts if omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file to use, defaults to `yYYYY/day_XX/input.txt`
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+        /// Records the results as the accepted answers in `yYYYY/day_XX/answers.toml`
+        #[arg(long, conflicts_with = "input")]
+        accept: bool,
+    },
+    /// Solves a part with the real input and submits the result
+    Submit {
+        /// Puzzle year, defaults to the latest year with solutions
+        #[arg(long, value_parser = clap::value_parser!(u64).range(2015..))]
+        year: Option<u64>,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+    /// Measures how long parsing and each part take
+    Bench {
+        /// Puzzle year, defaults to the latest year with solutions
+        #[arg(long, value_parser = clap::value_parser!(u64).range(2015..))]
+        year: Option<u64>,
+        /// Day to benchmark, benchmarks every day with an input if omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Input file to use, defaults to `yYYYY/day_XX/input.txt`
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+        /// Amount of measured runs per day
+        #[arg(long, default_value_t = 10)]
+        samples: usize,
+        /// Maximum seconds spent taking samples per day
+        #[arg(long, default_value_t = 5.0)]
+        max_time: f64,
+        /// Saves the results as a baseline with this name
+        #[arg(long)]
+        save_baseline: Option<String>,
+        /// Compares the results against the baseline with this name
+        #[arg(long)]
+        baseline: Option<String>,
+    },
+}
+
+fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    match args.command {
+        Command::Run { year, day, part, input, accept } => {
+            let year = year.unwrap_or_else(days::latest_year);
+            let parts: Vec<Part> = match part {
+                Some(part) => vec![part.try_into()?],
+                None => Part::ALL.to_vec(),
+            };
+
+            let mut mismatches = 0;
+            for day in selected_days(year, day) {
+                match &input {
+                    Some(input_path) => {
+                        run_day(year, day, &parts, input_path, None)?;
+                    }
+                    None => {
+                        let answers_path = Answers::path(&days::day_dir(year, day));
+                        let mut answers = Answers::load(&answers_path)?;
+                        let results = run_day(year, day, &parts, &default_input_path(year, day), Some(&answers))?;
+                        mismatches += results.iter()
+                            .filter(|(part, result)| matches!(answers.check(*part, result), Verdict::Mismatch { .. }))
+                            .count();
+                        if accept {
+                            for (part, result) in results {
+                                answers.set(part, &result);
+                            }
+                            answers.save(&answers_path)?;
+                            println!("Answers recorded in {}", answers_path.display());
+                        }
+                    }
+                }
+            }
+            // Accepting records the new results, so they aren't regressions
+            if mismatches > 0 && !accept {
+                anyhow::bail!("{mismatches} result/s don't match the accepted answers, use --accept if they are right");
+            }
+        }
+        Command::Submit { year, day, part } => {
+            let year = year.unwrap_or_else(days::latest_year);
+            submit_part(year, day, part.try_into()?)?;
+        }
+        Command::Bench { year, day, input, samples, max_time, save_baseline, baseline } => {
+            let year = year.unwrap_or_else(days::latest_year);
+            let options = BenchOptions { samples, max_time: Duration::from_secs_f64(max_time) };
+            let baseline = baseline
+                .map(|name| Baseline::load(&Baseline::path(year, &name)))
+                .transpose()?;
+
+            let mut results = Baseline::default();
+            for day in selected_days(year, day) {
+                let input_path = input.clone().unwrap_or_else(|| default_input_path(year, day));
+                if !input_path.exists() {
+                    println!("Skipping day {day}, {} not found.", input_path.display());
+                    continue;
+                }
+                let day_bench = bench_day(year, day, &input_path, &options)?;
+                results.days.insert(day, day_bench);
+            }
+
+            println!();
+            for (day, day_bench) in &results.days {
+                let day_baseline = baseline.as_ref().and_then(|b| b.days.get(day));
+                bench::print_report(*day, day_bench, day_baseline);
+            }
+
+            if let Some(name) = save_baseline {
+                let path = Baseline::path(year, &name);
+                results.save(&path)?;
+                println!("Baseline saved to {}", path.display());
+            }
+        }
+    }
+    Ok(())
+}
+
+fn selected_days(year: u64, day: Option<u8>) -> Vec<u8> {
+    match day {
+        Some(day) => vec![day],
+        None => days::available_days(year).collect(),
+    }
+}
+
+/// Solves the given parts, comparing them against `answers` when running on the real input.
+fn run_day(year: u64, day: u8, parts: &[Part], input_path: &Path, answers: Option<&Answers>) -> anyhow::Result<Vec<(Part, String)>> {
+    let solution = days::get_solution(year, day)
+        .with_context(|| format!("Day {day} of {year} has no registered solution."))?;
+    let input = std::fs::read_to_string(input_path)
+        .with_context(|| format!("Error reading input file {}.", input_path.display()))?;
+
+    let mut results = vec![];
+    for &part in parts {
+        println!("Day {day} - {part}:");
+        let result = solution.solve(&input, part)?;
+        display_result(&result);
+        if let Some(answers) = answers {
+            println!("Answer: {}", answers.check(part, &result));
+        }
+        println!();
+        results.push((part, result));
+    }
+    Ok(results)
+}
+
+/// Submits the result of `part`, recording the outcome in the day's `submissions.toml`,
+/// and in `answers.toml` once it's correct.
+fn submit_part(year: u64, day: u8, part: Part) -> anyhow::Result<()> {
+    let day_dir = days::day_dir(year, day);
+    let answers_path = Answers::path(&day_dir);
+    let mut answers = Answers::load(&answers_path)?;
+    let (_, answer) = run_day(year, day, &[part], &default_input_path(year, day), Some(&answers))?.remove(0);
+
+    input::load_dotenv()?;
+    let client = HttpInput::from_env();
+    let ledger_path = Ledger::path(&day_dir);
+    let mut ledger = Ledger::load(&ledger_path)?;
+    let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build()?;
+    let outcome = runtime.block_on(submit::submit(&client, &mut ledger, year, day, part, &answer));
+    // Whatever was recorded is kept, even if the outcome couldn't be parsed
+    ledger.save(&ledger_path)?;
+
+    let outcome = outcome?;
+    println!("Submitted {answer}: {outcome}");
+    if outcome == Outcome::Correct {
+        answers.set(part, &answer);
+        answers.save(&answers_path)?;
+        println!("Answer recorded in {}", answers_path.display());
+    }
+    Ok(())
+}
+
+fn bench_day(year: u64, day: u8, input_path: &Path, options: &BenchOptions) -> anyhow::Result<bench::DayBench> {
+    let solution = days::get_solution(year, day)
+        .with_context(|| format!("Day {day} of {year} has no registered solution."))?;
+    let input = std::fs::read_to_string(input_path)
+        .with_context(|| format!("Error reading input file {}.", input_path.display()))?;
+
+    println!("Benchmarking day {day}...");
+    bench::bench_day(solution, &input, options)
+}
+
+fn default_input_path(year: u64, day: u8) -> PathBuf {
+    days::day_dir(year, day).join("input.txt")
+}
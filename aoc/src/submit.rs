@@ -0,0 +1,269 @@
+//! Submission of answers, with a local ledger of everything already submitted.
+//!
+//! It lives next to the day's input as `submissions.toml`, and is checked before posting
+//! anything: known-wrong answers (including the ones out of the known too high / too low
+//! bounds) are refused, and so is submitting again before the wait asked by the server is
+//! over.
+
+use std::{fmt::Display, path::{Path, PathBuf}, time::{Duration, SystemTime, UNIX_EPOCH}};
+use anyhow::{bail, Context};
+use create_new_day::input::HttpInput;
+use serde::{Deserialize, Serialize};
+use xmas::solution::Part;
+
+/// What the server said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// Submitted too recently, with the seconds left to wait
+    Wait(u64),
+    /// The part is already solved, or isn't unlocked yet
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Parses the page returned by the server after submitting an answer.
+    pub fn parse(page: &str) -> anyhow::Result<Self> {
+        Ok(if page.contains("That's the right answer") {
+            Outcome::Correct
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if page.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Incorrect
+            }
+        } else if page.contains("You gave an answer too recently") {
+            Outcome::Wait(parse_wait(page).unwrap_or(60))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            bail!("Unexpected response to the submission:\n{page}")
+        })
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::Incorrect | Outcome::TooHigh | Outcome::TooLow)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::Incorrect => write!(f, "incorrect"),
+            Outcome::TooHigh => write!(f, "incorrect, too high"),
+            Outcome::TooLow => write!(f, "incorrect, too low"),
+            Outcome::Wait(seconds) => write!(f, "submitted too recently, wait {seconds}s"),
+            Outcome::WrongLevel => write!(f, "part already solved or still locked"),
+        }
+    }
+}
+
+/// Parses the `You have 1m 23s left to wait.` of a rate limited submission.
+fn parse_wait(page: &str) -> Option<u64> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+    page[start..end].split_whitespace()
+        .map(|amount| {
+            let (number, unit) = amount.split_at(amount.find(|ch: char| !ch.is_ascii_digit())?);
+            let number: u64 = number.parse().ok()?;
+            match unit {
+                "h" => Some(number * 3600),
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Seconds since the UNIX epoch
+    pub submitted_at: u64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Ledger {
+    #[serde(default)]
+    pub submissions: Vec<Submission>,
+}
+
+impl Ledger {
+    pub fn path(day_dir: &Path) -> PathBuf {
+        day_dir.join("submissions.toml")
+    }
+
+    /// Loads the ledger at `path`, a missing file is an empty ledger.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Error reading submissions {}.", path.display()))?;
+        toml::from_str(&content)
+            .with_context(|| format!("Invalid submissions file {}.", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(path, toml::to_string(self)?)
+            .with_context(|| format!("Error writing submissions {}.", path.display()))
+    }
+
+    fn of_part(&self, part: Part) -> impl Iterator<Item = &Submission> {
+        self.submissions.iter().filter(move |s| s.part == part.number())
+    }
+
+    /// Fails if submitting `answer` is pointless or not allowed yet, `now` being seconds
+    /// since the UNIX epoch.
+    pub fn check(&self, part: Part, answer: &str, now: u64) -> anyhow::Result<()> {
+        let number: Option<i64> = answer.parse().ok();
+        for submission in self.of_part(part) {
+            let submitted: Option<i64> = submission.answer.parse().ok();
+            match submission.outcome {
+                Outcome::Correct => bail!("{part} is already solved, the answer was {}.", submission.answer),
+                outcome if outcome.is_wrong() && submission.answer == answer => {
+                    bail!("{answer} was already submitted for {part}, it's {outcome}.")
+                }
+                Outcome::TooHigh if number.zip(submitted).is_some_and(|(n, high)| n >= high) => {
+                    bail!("{answer} can't be right, {} was already too high.", submission.answer)
+                }
+                Outcome::TooLow if number.zip(submitted).is_some_and(|(n, low)| n <= low) => {
+                    bail!("{answer} can't be right, {} was already too low.", submission.answer)
+                }
+                _ => (),
+            }
+        }
+
+        let wait_until = self.submissions.iter()
+            .filter_map(|s| match s.outcome {
+                Outcome::Wait(seconds) => Some(s.submitted_at + seconds),
+                _ => None,
+            })
+            .max();
+        if let Some(wait_until) = wait_until.filter(|&until| until > now) {
+            bail!("Submitted too recently, wait {}s before trying again.", wait_until - now);
+        }
+        Ok(())
+    }
+
+    pub fn record(&mut self, part: Part, answer: &str, outcome: Outcome, now: u64) {
+        self.submissions.push(Submission { part: part.number(), answer: answer.to_string(), outcome, submitted_at: now });
+    }
+}
+
+pub fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or(Duration::ZERO).as_secs()
+}
+
+/// Submits `answer` unless the ledger knows it's pointless, and records the outcome.
+pub async fn submit(client: &HttpInput, ledger: &mut Ledger, year: u64, day: u8, part: Part, answer: &str) -> anyhow::Result<Outcome> {
+    ledger.check(part, answer, now())?;
+    let page = client.submit(year, day, part.number(), answer).await?;
+    let outcome = Outcome::parse(&page)?;
+    ledger.record(part, answer, outcome, now());
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::{BufRead, BufReader, Read, Write}, net::TcpListener};
+
+    use super::*;
+
+    /// Answers every request with `page`, like the answer route of the website.
+    fn serve(page: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut content_length = 0;
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 2 {
+                    if let Some(len) = line.to_lowercase().strip_prefix("content-length: ") {
+                        content_length = len.trim().parse().unwrap();
+                    }
+                    line.clear();
+                }
+                reader.read_exact(&mut vec![0; content_length]).unwrap();
+                write!(stream, "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{page}", page.len()).unwrap();
+            }
+        });
+        base_url
+    }
+
+    fn ledger(submissions: &[(u8, &str, Outcome)]) -> Ledger {
+        Ledger {
+            submissions: submissions.iter()
+                .map(|&(part, answer, outcome)| Submission { part, answer: answer.to_string(), outcome, submitted_at: 1000 })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn parses_the_responses() {
+        let wrong = "<article><p>That's not the right answer; your answer is too high. Please wait one minute.</p></article>";
+        assert_eq!(Outcome::parse(wrong).unwrap(), Outcome::TooHigh);
+        assert_eq!(Outcome::parse(&wrong.replace("high", "low")).unwrap(), Outcome::TooLow);
+        assert_eq!(Outcome::parse("<p>That's not the right answer. If you're stuck...</p>").unwrap(), Outcome::Incorrect);
+        assert_eq!(Outcome::parse("<p>That's the right answer! You are one gold star closer.</p>").unwrap(), Outcome::Correct);
+        assert_eq!(Outcome::parse("<p>You gave an answer too recently. You have 1m 23s left to wait.</p>").unwrap(), Outcome::Wait(83));
+        assert_eq!(Outcome::parse("<p>You don't seem to be solving the right level.</p>").unwrap(), Outcome::WrongLevel);
+        assert!(Outcome::parse("<p>Please log in.</p>").is_err());
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let ledger = ledger(&[(1, "50", Outcome::TooHigh), (1, "10", Outcome::TooLow), (1, "30", Outcome::Incorrect)]);
+
+        for answer in ["50", "60", "10", "5", "30"] {
+            assert!(ledger.check(Part::One, answer, 2000).is_err(), "{answer}");
+        }
+        assert!(ledger.check(Part::One, "20", 2000).is_ok());
+        assert!(ledger.check(Part::Two, "30", 2000).is_ok());
+    }
+
+    #[test]
+    fn refuses_solved_parts_and_waits() {
+        assert!(ledger(&[(2, "7", Outcome::Correct)]).check(Part::Two, "8", 2000).is_err());
+
+        let rate_limited = ledger(&[(1, "7", Outcome::Wait(30))]);
+        assert!(rate_limited.check(Part::One, "7", 1010).is_err());
+        assert!(rate_limited.check(Part::One, "7", 1030).is_ok());
+    }
+
+    #[test]
+    fn ledger_roundtrips() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = Ledger::path(dir.path());
+        let ledger = ledger(&[(1, "7", Outcome::TooLow), (1, "9", Outcome::Wait(30))]);
+
+        ledger.save(&path).unwrap();
+
+        assert_eq!(Ledger::load(&path).unwrap(), ledger);
+    }
+
+    #[tokio::test]
+    async fn records_the_outcome_of_submissions() {
+        let client = HttpInput::new(serve("<article><p>That's not the right answer; your answer is too low.</p></article>"), Some("abc".to_string()));
+        let mut ledger = Ledger::default();
+
+        let outcome = submit(&client, &mut ledger, 2024, 1, Part::One, "42").await.unwrap();
+
+        assert_eq!(outcome, Outcome::TooLow);
+        assert_eq!(ledger.submissions.len(), 1);
+        assert!(submit(&client, &mut ledger, 2024, 1, Part::One, "41").await.is_err());
+        assert_eq!(ledger.submissions.len(), 1);
+    }
+}
@@ -0,0 +1,26 @@
+use std::path::Path;
+use xmas::{fixture::Example, solution::Part};
+
+fn check_example(year: u64, day: u8, sidecar_path: &str) {
+    let solution = aoc::days::get_solution(year, day)
+        .unwrap_or_else(|| panic!("Day {day} of {year} has examples but isn't registered in aoc/src/days.rs"));
+    let example = Example::load(Path::new(sidecar_path)).unwrap();
+    if let Err(err) = example.check(solution) {
+        panic!("{err:#}");
+    }
+}
+
+#[test]
+fn params_reject_unknown_keys() {
+    let params = toml::Table::from_iter([("not_a_param".to_string(), toml::Value::Integer(1))]);
+    for &year in aoc::days::YEARS {
+        for day in aoc::days::available_days(year) {
+            let solution = aoc::days::get_solution(year, day).unwrap();
+            let err = solution.solve_with_params("", Part::One, &params)
+                .expect_err(&format!("day {day} of {year} accepted an unknown param"));
+            assert!(format!("{err:#}").starts_with("Invalid params"), "day {day} of {year}: {err:#}");
+        }
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
@@ -0,0 +1,334 @@
+//! Where the puzzle inputs and descriptions come from.
+//!
+//! The input (or description) of a day is looked up in the local cache first and only
+//! downloaded when it's missing, so creating a day again never hits the network. For tests
+//! or offline work the download can be pointed at another server (`AOC_BASE_URL`) or
+//! replaced by a directory with the files already in it (`--inputs-dir`).
+//!
+//! The `aoc` runner uses the same [`HttpInput`] to submit answers.
+
+use std::path::{Path, PathBuf};
+use anyhow::{bail, Context};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// What can be fetched for a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resource {
+    Input,
+    /// The puzzle page, as HTML
+    Description,
+}
+
+impl Resource {
+    /// Name of the file holding the resource in a cache or inputs directory.
+    pub fn file_name(&self, day: u8) -> String {
+        match self {
+            Resource::Input => format!("day_{:02}.txt", day),
+            Resource::Description => format!("day_{:02}.html", day),
+        }
+    }
+
+    /// Whether `content` won't change anymore. The part 2 description only shows up once
+    /// part 1 is solved, so a page without it gets fetched again.
+    pub fn is_complete(&self, content: &str) -> bool {
+        match self {
+            Resource::Input => true,
+            Resource::Description => content.contains(r#"id="part2""#),
+        }
+    }
+
+    fn url(&self, base_url: &str, year: u64, day: u8) -> String {
+        let day_url = format!("{}/{}/day/{}", base_url.trim_end_matches('/'), year, day);
+        match self {
+            Resource::Input => format!("{day_url}/input"),
+            Resource::Description => day_url,
+        }
+    }
+}
+
+// Only used by the workspace binaries, which don't need the futures to be `Send`
+#[allow(async_fn_in_trait)]
+pub trait PuzzleProvider {
+    async fn fetch(&self, year: u64, day: u8, resource: Resource) -> anyhow::Result<String>;
+}
+
+/// Loads the variables in `.env` (like `AOC_SESSION`), the ones already set take precedence.
+pub fn load_dotenv() -> anyhow::Result<()> {
+    match dotenvy::dotenv() {
+        Ok(_) => Ok(()),
+        Err(err) if err.not_found() => Ok(()),
+        Err(err) => Err(err).context("Invalid .env file."),
+    }
+}
+
+/// Downloads from the Advent of Code website, or any server with the same routes.
+pub struct HttpInput {
+    base_url: String,
+    session: Option<String>,
+}
+
+impl HttpInput {
+    pub fn new(base_url: impl Into<String>, session: Option<String>) -> Self {
+        Self { base_url: base_url.into(), session }
+    }
+
+    /// Uses `AOC_BASE_URL` (defaults to the real website) and the `AOC_SESSION` cookie.
+    pub fn from_env() -> Self {
+        let base_url = std::env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        Self::new(base_url, std::env::var("AOC_SESSION").ok())
+    }
+
+    /// Posts `answer` for a part of the puzzle, returns the page with the response.
+    pub async fn submit(&self, year: u64, day: u8, part: u8, answer: &str) -> anyhow::Result<String> {
+        let url = format!("{}/answer", Resource::Description.url(&self.base_url, year, day));
+        let level = part.to_string();
+        let response = self.request(reqwest::Client::new().post(&url), &url)?
+            .form(&[("level", level.as_str()), ("answer", answer)])
+            .send()
+            .await
+            .with_context(|| format!("Error posting to {url}."))?
+            .error_for_status()
+            .with_context(|| format!("Server error posting to {url}."))?;
+        Ok(response.text().await?)
+    }
+
+    /// Adds the session cookie to `request`.
+    fn request(&self, request: reqwest::RequestBuilder, url: &str) -> anyhow::Result<reqwest::RequestBuilder> {
+        let Some(session) = &self.session else {
+            bail!("AOC_SESSION isn't set, it's required for {url}.");
+        };
+        Ok(request.header("Cookie", format!("session={}", session)))
+    }
+}
+
+impl PuzzleProvider for HttpInput {
+    async fn fetch(&self, year: u64, day: u8, resource: Resource) -> anyhow::Result<String> {
+        let url = resource.url(&self.base_url, year, day);
+        let response = self.request(reqwest::Client::new().get(&url), &url)?
+            .send()
+            .await
+            .with_context(|| format!("Error requesting {url}."))?
+            .error_for_status()
+            .with_context(|| format!("Server error downloading {url}."))?;
+        Ok(response.text().await?)
+    }
+}
+
+/// Reads from a directory holding `day_XX.txt` inputs and `day_XX.html` descriptions.
+pub struct DirInput {
+    dir: PathBuf,
+}
+
+impl DirInput {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+}
+
+impl PuzzleProvider for DirInput {
+    async fn fetch(&self, _year: u64, day: u8, resource: Resource) -> anyhow::Result<String> {
+        let path = self.dir.join(resource.file_name(day));
+        std::fs::read_to_string(&path)
+            .with_context(|| format!("Error reading {}.", path.display()))
+    }
+}
+
+/// Looks everything up in `cache_dir` first, only asking `inner` for what's missing or
+/// [incomplete](Resource::is_complete), which is stored for the next time.
+pub struct CachedInput<P> {
+    cache_dir: PathBuf,
+    inner: P,
+}
+
+impl<P: PuzzleProvider> CachedInput<P> {
+    pub fn new(cache_dir: impl Into<PathBuf>, inner: P) -> Self {
+        Self { cache_dir: cache_dir.into(), inner }
+    }
+
+    pub fn path(&self, year: u64, day: u8, resource: Resource) -> PathBuf {
+        self.cache_dir.join(year.to_string()).join(resource.file_name(day))
+    }
+}
+
+impl<P: PuzzleProvider> PuzzleProvider for CachedInput<P> {
+    async fn fetch(&self, year: u64, day: u8, resource: Resource) -> anyhow::Result<String> {
+        let path = self.path(year, day, resource);
+        let cached = if path.exists() {
+            let cached = std::fs::read_to_string(&path)
+                .with_context(|| format!("Error reading cached {}.", path.display()))?;
+            if resource.is_complete(&cached) {
+                return Ok(cached);
+            }
+            Some(cached)
+        } else {
+            None
+        };
+
+        let content = match (self.inner.fetch(year, day, resource).await, cached) {
+            (Ok(content), _) => content,
+            // Offline, what was cached is better than nothing
+            (Err(_), Some(cached)) => return Ok(cached),
+            (Err(err), None) => return Err(err),
+        };
+        write_creating_dirs(&path, &content)
+            .with_context(|| format!("Error caching {}.", path.display()))?;
+        Ok(content)
+    }
+}
+
+fn write_creating_dirs(path: &Path, content: &str) -> std::io::Result<()> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, content)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, io::{BufRead, BufReader, Read, Write}, net::TcpListener, sync::mpsc};
+
+    use super::*;
+
+    /// Counts how many times it's asked for something.
+    struct CountingInput {
+        calls: Cell<usize>,
+    }
+
+    impl PuzzleProvider for CountingInput {
+        async fn fetch(&self, _year: u64, day: u8, resource: Resource) -> anyhow::Result<String> {
+            self.calls.set(self.calls.get() + 1);
+            Ok(format!("{resource:?} {day}"))
+        }
+    }
+
+    /// Serves a single request with `body`, sends back the request (head and body).
+    fn serve_once(status: &'static str, body: &'static str) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut head = String::new();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                head.push_str(&line);
+            }
+            let content_length = head.lines()
+                .find_map(|line| line.to_lowercase().strip_prefix("content-length: ").map(|len| len.parse().unwrap()))
+                .unwrap_or(0);
+            let mut request_body = vec![0; content_length];
+            reader.read_exact(&mut request_body).unwrap();
+            head.push_str(&String::from_utf8(request_body).unwrap());
+            write!(stream, "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len()).unwrap();
+            sender.send(head).unwrap();
+        });
+        (base_url, receiver)
+    }
+
+    #[tokio::test]
+    async fn only_asks_for_missing_inputs() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let provider = CachedInput::new(cache_dir.path(), CountingInput { calls: Cell::new(0) });
+
+        assert_eq!(provider.fetch(2024, 3, Resource::Input).await.unwrap(), "Input 3");
+        assert_eq!(provider.fetch(2024, 3, Resource::Input).await.unwrap(), "Input 3");
+        assert_eq!(provider.fetch(2024, 3, Resource::Description).await.unwrap(), "Description 3");
+
+        assert_eq!(provider.inner.calls.get(), 2);
+        assert!(cache_dir.path().join("2024").join("day_03.txt").exists());
+        assert!(cache_dir.path().join("2024").join("day_03.html").exists());
+    }
+
+    #[tokio::test]
+    async fn fetches_descriptions_again_until_they_have_part_2() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let provider = CachedInput::new(cache_dir.path(), CountingInput { calls: Cell::new(0) });
+        let cached = provider.path(2024, 3, Resource::Description);
+        write_creating_dirs(&cached, "<article>Part 1</article>").unwrap();
+
+        assert_eq!(provider.fetch(2024, 3, Resource::Description).await.unwrap(), "Description 3");
+        assert_eq!(provider.inner.calls.get(), 1);
+
+        let complete = r#"<article>Part 1</article><article><h2 id="part2">"#;
+        std::fs::write(&cached, complete).unwrap();
+        assert_eq!(provider.fetch(2024, 3, Resource::Description).await.unwrap(), complete);
+        assert_eq!(provider.inner.calls.get(), 1);
+    }
+
+    #[tokio::test]
+    async fn keeps_incomplete_descriptions_when_offline() {
+        let cache_dir = tempfile::tempdir().unwrap();
+        let offline = tempfile::tempdir().unwrap();
+        let provider = CachedInput::new(cache_dir.path(), DirInput::new(offline.path()));
+        write_creating_dirs(&provider.path(2024, 3, Resource::Description), "<article>Part 1</article>").unwrap();
+
+        assert_eq!(provider.fetch(2024, 3, Resource::Description).await.unwrap(), "<article>Part 1</article>");
+        assert!(provider.fetch(2024, 4, Resource::Description).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn reads_inputs_from_a_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("day_07.txt"), "190: 10 19\n").unwrap();
+
+        let provider = DirInput::new(dir.path());
+
+        assert_eq!(provider.fetch(2024, 7, Resource::Input).await.unwrap(), "190: 10 19\n");
+        assert!(provider.fetch(2024, 7, Resource::Description).await.is_err());
+        assert!(provider.fetch(2024, 8, Resource::Input).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn downloads_with_the_session_cookie() {
+        let (base_url, request) = serve_once("200 OK", "3   4\n4   3\n");
+        let provider = HttpInput::new(base_url, Some("abc".to_string()));
+
+        let input = provider.fetch(2024, 1, Resource::Input).await.unwrap();
+
+        let request = request.recv().unwrap();
+        assert_eq!(input, "3   4\n4   3\n");
+        assert!(request.starts_with("GET /2024/day/1/input "));
+        assert!(request.to_lowercase().contains("cookie: session=abc"));
+    }
+
+    #[tokio::test]
+    async fn downloads_the_puzzle_page() {
+        let (base_url, request) = serve_once("200 OK", "<article></article>");
+        let provider = HttpInput::new(base_url, Some("abc".to_string()));
+
+        let page = provider.fetch(2024, 12, Resource::Description).await.unwrap();
+
+        assert_eq!(page, "<article></article>");
+        assert!(request.recv().unwrap().starts_with("GET /2024/day/12 "));
+    }
+
+    #[tokio::test]
+    async fn posts_answers_with_the_session_cookie() {
+        let (base_url, request) = serve_once("200 OK", "<article><p>That's the right answer!</p></article>");
+        let provider = HttpInput::new(base_url, Some("abc".to_string()));
+
+        let page = provider.submit(2024, 5, 2, "6,1").await.unwrap();
+
+        let request = request.recv().unwrap();
+        assert!(page.contains("That's the right answer!"));
+        assert!(request.starts_with("POST /2024/day/5/answer "));
+        assert!(request.to_lowercase().contains("cookie: session=abc"));
+        assert!(request.ends_with("level=2&answer=6%2C1"));
+    }
+
+    #[tokio::test]
+    async fn fails_without_session_or_on_server_errors() {
+        let without_session = HttpInput::new("http://127.0.0.1:1", None);
+        assert!(without_session.fetch(2024, 1, Resource::Input).await.is_err());
+
+        let (base_url, _request) = serve_once("404 Not Found", "");
+        let provider = HttpInput::new(base_url, Some("abc".to_string()));
+        assert!(provider.fetch(2024, 1, Resource::Input).await.is_err());
+    }
+}
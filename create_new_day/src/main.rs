@@ -1,62 +1,112 @@
-use std::{io::Cursor, process::Command};
+use std::path::{Path, PathBuf};
+use anyhow::Context;
 use clap::Parser;
 use color_print::cprintln;
-use tokio;
-
-const YEAR: u64 = 2024;
+use create_new_day::{
+    input::{self, CachedInput, DirInput, HttpInput, PuzzleProvider, Resource},
+    markdown,
+    scaffold::{self, Kind, StagedDay, TemplateVars},
+};
 
 #[derive(Parser, Debug)]
 struct Args {
-    day_number: usize,
+    day_number: u8,
+    /// Puzzle year, defaults to the latest `yYYYY` directory of the workspace
+    #[arg(long, value_parser = clap::value_parser!(u64).range(2015..))]
+    year: Option<u64>,
+    /// Template the day starts from
+    #[arg(long, value_enum, default_value_t)]
+    kind: Kind,
+    /// Directory where downloaded inputs and descriptions are kept, they are never downloaded twice
+    #[arg(long, default_value = ".aoc-cache")]
+    cache_dir: PathBuf,
+    /// Takes the input from `day_XX.txt` (and the puzzle page from `day_XX.html`) in this
+    /// directory instead of downloading them
+    #[arg(long)]
+    inputs_dir: Option<PathBuf>,
 }
 
 #[tokio::main]
-async fn main() {
+async fn main() -> anyhow::Result<()> {
+    input::load_dotenv()?;
     let args = Args::parse();
+    let root = Path::new(".");
+
+    let year = match args.year {
+        Some(year) => year,
+        None => scaffold::latest_year(root)?
+            .context("There are no years in the workspace yet, pass one with --year.")?,
+    };
+    let vars = TemplateVars::new(args.day_number, year);
+    match &args.inputs_dir {
+        Some(dir) => create_day(root, args.kind, &vars, &CachedInput::new(&args.cache_dir, DirInput::new(dir))).await,
+        None => create_day(root, args.kind, &vars, &CachedInput::new(&args.cache_dir, HttpInput::from_env())).await,
+    }
+}
+
+async fn create_day(root: &Path, kind: Kind, vars: &TemplateVars, provider: &impl PuzzleProvider) -> anyhow::Result<()> {
+    let member = vars.member();
+    if scaffold::day_exists(root, &member)? {
+        let day_dir = root.join(&member);
+        if day_dir.join("input.txt").exists() {
+            cprintln!("🎁 <yellow>{member} already exists</> and has its input.");
+        } else {
+            cprintln!("🎁 <yellow>{member} already exists</>, getting its missing input...");
+            save_input(provider, vars, &day_dir).await?;
+        }
+        if !day_dir.join(README).exists() {
+            println!("📖 Getting the puzzle description...");
+            save_description(provider, vars, &day_dir).await;
+        }
+        return Ok(());
+    }
+
+    // Everything is prepared in a staging directory, which is deleted if any step fails
+    println!("🎁 Creating crate {member}...");
+    let staged = StagedDay::create(root, kind, vars)?;
+
+    println!("📋 Getting input...");
+    save_input(provider, vars, staged.path()).await?;
+
+    println!("📖 Getting the puzzle description...");
+    save_description(provider, vars, staged.path()).await;
+
+    println!("📝 Adding it to the workspace...");
+    staged.commit()?;
+
+    cprintln!("🎄 <green>Done!</> Don't let Santa down and don't forget to register it in <yellow>aoc/Cargo.toml</> and <yellow>aoc/src/days.rs</>, then run:");
+    cprintln!("   <yellow>cargo run --bin aoc -- run --year {} --day {}</>", vars.year, vars.day);
+    cprintln!("   Its examples are tested once registered, add their answers in <yellow>examples/example_1.toml</>.");
+    Ok(())
+}
+
+const README: &str = "README.md";
+const EXAMPLE: &str = "examples/example_1.txt";
+
+async fn save_input(provider: &impl PuzzleProvider, vars: &TemplateVars, day_dir: &Path) -> anyhow::Result<()> {
+    let input = provider.fetch(vars.year, vars.day, Resource::Input).await?;
+    std::fs::write(day_dir.join("input.txt"), input)?;
+    Ok(())
+}
+
+/// Writes the description as `README.md` and its first example as `examples/example_1.txt`,
+/// unless that one already has content. The day can be solved without them, so failing
+/// only prints a warning.
+async fn save_description(provider: &impl PuzzleProvider, vars: &TemplateVars, day_dir: &Path) {
+    if let Err(err) = try_save_description(provider, vars, day_dir).await {
+        cprintln!("⚠️  <yellow>Couldn't save the puzzle description:</> {err:#}");
+    }
+}
+
+async fn try_save_description(provider: &impl PuzzleProvider, vars: &TemplateVars, day_dir: &Path) -> anyhow::Result<()> {
+    let page = provider.fetch(vars.year, vars.day, Resource::Description).await?;
+    std::fs::write(day_dir.join(README), markdown::puzzle_to_markdown(&page)?)?;
 
-    // TODO: Recover from these errors and delete incomplete crates
-    let crate_name = format!("day_{:02}", args.day_number);
-    println!("🎁 Creating crate {crate_name}...");
-
-    Command::new("cargo")
-        .args(["workspaces", "create"])
-        .args(["--bin", &crate_name])
-        .args(["--name", &crate_name])
-        .args(["--edition", "2021"])
-        // .stderr(std::io::stderr())
-        .output()
-        .expect("Failed to create cargo library.");
-
-    // TODO: Use cross-platform code
-    println!("📝 Preparing files...");
-    Command::new("cp")
-        .args(["-a", "template/.", &crate_name])
-        .stderr(std::io::stderr())
-        .output()
-        .expect("Failed to copy contents from template.");
-
-    Command::new("sed")
-        .args(["-i", &format!(r#"s/name = "template"/name = "{}"/g"#, crate_name), &format!("./{}/Cargo.toml", crate_name)])
-        .stderr(std::io::stderr())
-        .output()
-        .expect("Failed to copy contents from template.");
-
-    println!("📋 Downloading input...");
-    let client = reqwest::Client::new();
-    let session = std::env::var("AOC_SESSION").expect("Invalid AOC_SESSION env variable.");
-    let result = client.get(format!("https://adventofcode.com/{}/day/{}/input", YEAR, args.day_number))
-        .header("Cookie", format!("session={}", session))
-        .send()
-        .await
-        .expect("Client error downloading input!")
-        .error_for_status()
-        .expect("Server error downloading input!");
-
-    let mut file = std::fs::File::create(format!("{crate_name}/input.txt")).unwrap();
-    let mut content = Cursor::new(result.bytes().await.unwrap());
-
-    std::io::copy(&mut content, &mut file).unwrap();
-
-    cprintln!("🎄 <green>Done!</> Don't let Santa down and don't forget to run:");
-    cprintln!("   <yellow>cd {crate_name}</>");
+    let example_path = day_dir.join(EXAMPLE);
+    let example_is_empty = std::fs::read_to_string(&example_path).map_or(true, |example| example.trim().is_empty());
+    if let (Some(example), true) = (markdown::first_example(&page), example_is_empty) {
+        std::fs::create_dir_all(example_path.parent().unwrap())?;
+        std::fs::write(example_path, example)?;
+    }
+    Ok(())
 }
@@ -0,0 +1,289 @@
+//! Conversion of the puzzle page to markdown.
+//!
+//! Only the handful of tags used in the puzzle descriptions are supported (titles,
+//! paragraphs, lists, links, emphasis and code), anything else is dropped keeping its text.
+
+use anyhow::bail;
+
+use crate::input::DEFAULT_BASE_URL;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token<'a> {
+    Open { name: &'a str, attrs: &'a str },
+    Close(&'a str),
+    Text(&'a str),
+}
+
+/// Converts the descriptions (`<article>`s) of a puzzle page to markdown.
+pub fn puzzle_to_markdown(page: &str) -> anyhow::Result<String> {
+    let articles = articles(page);
+    if articles.is_empty() {
+        bail!("The page has no puzzle description.");
+    }
+    let markdown: Vec<_> = articles.into_iter().map(to_markdown).collect();
+    Ok(markdown.join("\n"))
+}
+
+/// The first `<pre><code>` block of the descriptions, which is usually the example input.
+pub fn first_example(page: &str) -> Option<String> {
+    articles(page).into_iter().find_map(|article| {
+        let start = article.find("<pre><code>")? + "<pre><code>".len();
+        let end = start + article[start..].find("</code></pre>")?;
+        let text: String = tokens(&article[start..end]).into_iter()
+            .filter_map(|token| match token {
+                Token::Text(text) => Some(decode_entities(text)),
+                _ => None,
+            })
+            .collect();
+        Some(text)
+    })
+}
+
+fn articles(page: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = page;
+    while let Some(start) = rest.find("<article") {
+        let Some(content_start) = rest[start..].find('>').map(|i| start + i + 1) else {
+            break;
+        };
+        let Some(end) = rest[content_start..].find("</article>").map(|i| content_start + i) else {
+            break;
+        };
+        articles.push(&rest[content_start..end]);
+        rest = &rest[end..];
+    }
+    articles
+}
+
+fn to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut in_pre = false;
+    let mut in_code = false;
+    let mut title_start = None;
+    let mut links: Vec<Option<&str>> = vec![];
+
+    for token in tokens(html) {
+        match token {
+            Token::Open { name: "h2", .. } => {
+                markdown.push_str("## ");
+                title_start = Some(markdown.len());
+            }
+            Token::Close("h2") => {
+                // Titles look like `--- Day 1: Historian Hysteria ---`
+                if let Some(start) = title_start.take() {
+                    let title = markdown[start..].trim_matches(['-', ' ']).to_string();
+                    markdown.truncate(start);
+                    markdown.push_str(&title);
+                }
+                markdown.push_str("\n\n");
+            }
+            Token::Close("p") | Token::Close("ul") => {
+                let trimmed_len = markdown.trim_end().len();
+                markdown.truncate(trimmed_len);
+                markdown.push_str("\n\n");
+            }
+            Token::Open { name: "li", .. } => markdown.push_str("- "),
+            Token::Close("li") => {
+                let trimmed_len = markdown.trim_end().len();
+                markdown.truncate(trimmed_len);
+                markdown.push('\n');
+            }
+            Token::Open { name: "pre", .. } => {
+                in_pre = true;
+                markdown.push_str("```\n");
+            }
+            Token::Close("pre") => {
+                in_pre = false;
+                if !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+                markdown.push_str("```\n\n");
+            }
+            Token::Open { name: "code", .. } if !in_pre => {
+                in_code = true;
+                markdown.push('`');
+            }
+            Token::Close("code") if !in_pre => {
+                in_code = false;
+                markdown.push('`');
+            }
+            // Markdown has no emphasis inside code
+            Token::Open { name: "em", .. } | Token::Close("em") if !in_pre && !in_code => markdown.push('*'),
+            Token::Open { name: "a", attrs } => {
+                links.push(attribute(attrs, "href"));
+                markdown.push('[');
+            }
+            Token::Close("a") => match links.pop().flatten() {
+                Some(href) if href.starts_with('/') => markdown.push_str(&format!("]({DEFAULT_BASE_URL}{href})")),
+                Some(href) => markdown.push_str(&format!("]({href})")),
+                None => markdown.push(']'),
+            },
+            Token::Text(text) if in_pre => markdown.push_str(&decode_entities(text)),
+            Token::Text(text) => {
+                // Outside `<pre>` any whitespace is a single space, if not at the start of a line
+                let text = decode_entities(text);
+                let words = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                let at_line_start = markdown.is_empty() || markdown.ends_with(['\n', ' ']);
+                if text.starts_with(char::is_whitespace) && !at_line_start {
+                    markdown.push(' ');
+                }
+                markdown.push_str(&words);
+                if !words.is_empty() && text.ends_with(char::is_whitespace) {
+                    markdown.push(' ');
+                }
+            }
+            _ => (),
+        }
+    }
+
+    let mut markdown = markdown.trim_end().to_string();
+    markdown.push('\n');
+    markdown
+}
+
+fn tokens(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+        if rest[start..].starts_with("<!--") {
+            rest = rest[start..].find("-->").map_or("", |end| &rest[start + end + 3..]);
+            continue;
+        }
+        let Some(end) = rest[start..].find('>').map(|i| start + i) else {
+            tokens.push(Token::Text(&rest[start..]));
+            return tokens;
+        };
+
+        let tag = rest[start + 1..end].trim_end_matches('/');
+        match tag.strip_prefix('/') {
+            Some(name) => tokens.push(Token::Close(name.trim())),
+            None => {
+                let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+                tokens.push(Token::Open { name, attrs });
+            }
+        }
+        rest = &rest[end + 1..];
+    }
+    if !rest.is_empty() {
+        tokens.push(Token::Text(rest));
+    }
+    tokens
+}
+
+fn attribute<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let start = attrs.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = start + attrs[start..].find('"')?;
+    Some(&attrs[start..end])
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let ch = entity.and_then(|(entity, _)| match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => entity.strip_prefix('#')
+                .and_then(|code| match code.strip_prefix('x') {
+                    Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                    None => code.parse().ok(),
+                })
+                .and_then(char::from_u32),
+        });
+        match (ch, entity) {
+            (Some(ch), Some((_, end))) => {
+                decoded.push(ch);
+                rest = &rest[end + 1..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Historian Hysteria ---</h2><p>The <em>Chief Historian</em> is always present for the big Christmas sleigh launch.</p>
+<p>For example:</p>
+<pre><code>3   4
+4   3
+<em>2</em>   5
+</code></pre>
+<ul>
+<li>The smallest number in the left list is <code>1</code>, and the smallest in the right list is <code><em>3</em></code>.</li>
+<li>See <a href="/2024/about">the about page</a> &amp; <a href="https://example.com">this</a>.</li>
+</ul>
+<p>What is the total distance &lt;between&gt; your lists?</p>
+</article>
+<p>Your puzzle answer was <code>42</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Not <em>quite</em>.</p>
+<pre><code>x
+</code></pre>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn converts_the_descriptions() {
+        let markdown = puzzle_to_markdown(PAGE).unwrap();
+
+        assert_eq!(markdown, "\
+## Day 1: Historian Hysteria
+
+The *Chief Historian* is always present for the big Christmas sleigh launch.
+
+For example:
+
+```
+3   4
+4   3
+2   5
+```
+
+- The smallest number in the left list is `1`, and the smallest in the right list is `3`.
+- See [the about page](https://adventofcode.com/2024/about) & [this](https://example.com).
+
+What is the total distance <between> your lists?
+
+## Part Two
+
+Not *quite*.
+
+```
+x
+```
+");
+    }
+
+    #[test]
+    fn extracts_the_first_example() {
+        assert_eq!(first_example(PAGE).unwrap(), "3   4\n4   3\n2   5\n");
+        assert_eq!(first_example("<article><p>No code</p></article>"), None);
+    }
+
+    #[test]
+    fn fails_without_descriptions() {
+        assert!(puzzle_to_markdown("<html><p>Please log in</p></html>").is_err());
+    }
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(decode_entities("a &lt;&gt; &#39;b&#x27; &unknown; & c"), "a <> 'b' &unknown; & c");
+    }
+}
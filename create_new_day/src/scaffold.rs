@@ -0,0 +1,350 @@
+//! Creation of a day crate from one of the `templates`.
+//!
+//! Every text file of the template is rendered, replacing `{{crate_name}}`, `{{day}}` and
+//! `{{year}}` with the values of the new day. The package name in `Cargo.toml` is set
+//! directly, since the templates have to stay valid crates of the workspace. Days are
+//! namespaced per year, `yYYYY/day_XX` being the crate `yYYYY_day_XX`, at the same depth as
+//! `templates/<kind>` so the paths of their dependencies don't change.
+//!
+//! The crate is built in a staging directory next to where it will live, and only moved
+//! into place (and added to the workspace members) on [`StagedDay::commit`]. If anything
+//! fails before that, the staging directory is deleted, as is the year directory if it was
+//! created for this day, and the workspace is left untouched.
+
+use std::path::{Path, PathBuf};
+use anyhow::{bail, Context};
+use tempfile::TempDir;
+use toml_edit::{DocumentMut, Value};
+
+pub const TEMPLATES_DIR: &str = "templates";
+
+/// The template a day starts from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum Kind {
+    /// Input taken as is
+    #[default]
+    Plain,
+    /// `ByteMap` with path finding
+    Grid,
+    /// Lines of numbers extracted with a regex
+    Records,
+    /// Instructions run by a small machine
+    Vm,
+}
+
+impl Kind {
+    pub fn template_dir(&self, root: &Path) -> PathBuf {
+        let name = match self {
+            Kind::Plain => "plain",
+            Kind::Grid => "grid",
+            Kind::Records => "records",
+            Kind::Vm => "vm",
+        };
+        root.join(TEMPLATES_DIR).join(name)
+    }
+}
+
+/// Values substituted in the template files.
+#[derive(Debug, Clone)]
+pub struct TemplateVars {
+    pub crate_name: String,
+    pub day: u8,
+    pub year: u64,
+}
+
+impl TemplateVars {
+    pub fn new(day: u8, year: u64) -> Self {
+        Self { crate_name: format!("y{year}_day_{:02}", day), day, year }
+    }
+
+    /// Directory of the year, relative to the workspace root.
+    pub fn year_dir(&self) -> String {
+        format!("y{}", self.year)
+    }
+
+    /// Directory of the crate relative to the workspace root, also its workspace member.
+    pub fn member(&self) -> String {
+        format!("{}/day_{:02}", self.year_dir(), self.day)
+    }
+
+    pub fn render(&self, template: &str) -> String {
+        template
+            .replace("{{crate_name}}", &self.crate_name)
+            .replace("{{day}}", &self.day.to_string())
+            .replace("{{year}}", &self.year.to_string())
+    }
+}
+
+pub struct StagedDay {
+    root: PathBuf,
+    member: String,
+    staging: TempDir,
+    // After `staging`, so that it's empty by the time it's dropped
+    year_dir: CreatedDir,
+}
+
+impl StagedDay {
+    /// Renders the `kind` template of the workspace at `root` into a new staging directory.
+    pub fn create(root: &Path, kind: Kind, vars: &TemplateVars) -> anyhow::Result<Self> {
+        let member = vars.member();
+        if day_exists(root, &member)? {
+            bail!("{member} already exists.");
+        }
+
+        let year_dir = CreatedDir::create(root.join(vars.year_dir()))?;
+        let staging = tempfile::Builder::new()
+            .prefix(&format!(".day_{:02}-", vars.day))
+            .tempdir_in(year_dir.path())
+            .context("Error creating the staging directory.")?;
+        render_dir(&kind.template_dir(root), staging.path(), vars)
+            .context("Error rendering the template.")?;
+        rename_package(&staging.path().join("Cargo.toml"), &vars.crate_name)?;
+
+        Ok(Self { root: root.to_path_buf(), member, staging, year_dir })
+    }
+
+    /// Where the crate is being built, files can be added before committing.
+    pub fn path(&self) -> &Path {
+        self.staging.path()
+    }
+
+    /// Moves the crate into the workspace and adds it to the members.
+    pub fn commit(self) -> anyhow::Result<PathBuf> {
+        let manifest_path = self.root.join("Cargo.toml");
+        let manifest = add_member(&std::fs::read_to_string(&manifest_path)?, &self.member)?;
+
+        let target = self.root.join(&self.member);
+        let staging = self.staging.into_path();
+        if let Err(err) = std::fs::rename(&staging, &target) {
+            let _ = std::fs::remove_dir_all(&staging);
+            return Err(err).context("Error moving the crate into place.");
+        }
+
+        if let Err(err) = std::fs::write(&manifest_path, manifest) {
+            let _ = std::fs::remove_dir_all(&target);
+            return Err(err).context("Error adding the crate to the workspace members.");
+        }
+        self.year_dir.keep();
+        Ok(target)
+    }
+}
+
+/// A directory that is removed when dropped if it didn't exist before, unless it's kept.
+struct CreatedDir {
+    path: PathBuf,
+    created: bool,
+}
+
+impl CreatedDir {
+    fn create(path: PathBuf) -> anyhow::Result<Self> {
+        let created = !path.exists();
+        std::fs::create_dir_all(&path)
+            .with_context(|| format!("Error creating {}.", path.display()))?;
+        Ok(Self { path, created })
+    }
+
+    fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn keep(mut self) {
+        self.created = false;
+    }
+}
+
+impl Drop for CreatedDir {
+    fn drop(&mut self) {
+        if self.created {
+            // Only if empty, what's in it isn't ours
+            let _ = std::fs::remove_dir(&self.path);
+        }
+    }
+}
+
+/// A day exists if its directory does or if it's already a workspace member.
+pub fn day_exists(root: &Path, member: &str) -> anyhow::Result<bool> {
+    if root.join(member).exists() {
+        return Ok(true);
+    }
+    let manifest: DocumentMut = std::fs::read_to_string(root.join("Cargo.toml"))?.parse()?;
+    let exists = members(&manifest)?.iter().any(|m| m.as_str() == Some(member));
+    Ok(exists)
+}
+
+/// The most recent year with a `yYYYY` directory in the workspace at `root`.
+pub fn latest_year(root: &Path) -> anyhow::Result<Option<u64>> {
+    let mut latest = None;
+    for entry in std::fs::read_dir(root)? {
+        let entry = entry?;
+        let year = entry.file_name().to_str()
+            .and_then(|name| name.strip_prefix('y'))
+            .and_then(|year| year.parse::<u64>().ok());
+        if entry.file_type()?.is_dir() && year.is_some() {
+            latest = latest.max(year);
+        }
+    }
+    Ok(latest)
+}
+
+fn members(manifest: &DocumentMut) -> anyhow::Result<&toml_edit::Array> {
+    manifest.get("workspace")
+        .and_then(|w| w.get("members"))
+        .and_then(|m| m.as_array())
+        .context("The root Cargo.toml has no workspace members.")
+}
+
+/// Returns `manifest` with `member` appended to the workspace members, keeping the one
+/// member per line layout.
+fn add_member(manifest: &str, member: &str) -> anyhow::Result<String> {
+    let mut manifest: DocumentMut = manifest.parse()?;
+    members(&manifest)?;
+    let members = manifest["workspace"]["members"].as_array_mut().unwrap();
+
+    let mut member = Value::from(member);
+    member.decor_mut().set_prefix("\n    ");
+    members.push_formatted(member);
+    members.set_trailing_comma(true);
+    members.set_trailing("\n");
+    Ok(manifest.to_string())
+}
+
+fn rename_package(manifest_path: &Path, crate_name: &str) -> anyhow::Result<()> {
+    let mut manifest: DocumentMut = std::fs::read_to_string(manifest_path)?.parse()?;
+    manifest["package"]["name"] = toml_edit::value(crate_name);
+    std::fs::write(manifest_path, manifest.to_string())?;
+    Ok(())
+}
+
+/// Copies `from` into `to` rendering the text files, any other file is copied as is.
+fn render_dir(from: &Path, to: &Path, vars: &TemplateVars) -> std::io::Result<()> {
+    std::fs::create_dir_all(to)?;
+    for entry in std::fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            render_dir(&entry.path(), &target, vars)?;
+            continue;
+        }
+        match String::from_utf8(std::fs::read(entry.path())?) {
+            Ok(text) => std::fs::write(target, vars.render(&text))?,
+            Err(_) => { std::fs::copy(entry.path(), target)?; }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = "[workspace]\nmembers = [\n    \"xmas\",\n    \"y2024/day_01\",\n]\nresolver = \"2\"\n";
+
+    fn workspace() -> TempDir {
+        let root = tempfile::tempdir().unwrap();
+        std::fs::write(root.path().join("Cargo.toml"), MANIFEST).unwrap();
+        for kind in [Kind::Plain, Kind::Grid] {
+            let template = kind.template_dir(root.path());
+            std::fs::create_dir_all(template.join("src")).unwrap();
+            std::fs::write(template.join("Cargo.toml"), "[package]\nname = \"template\"\nversion = \"0.1.0\"\n\n[dependencies]\nxmas = { version = \"0.1.0\", path = \"../../xmas\" }\n").unwrap();
+            std::fs::write(template.join("src").join("lib.rs"), format!("//! {kind:?} day {{{{day}}}} of {{{{year}}}}\npub struct Puzzle;\n")).unwrap();
+        }
+        root
+    }
+
+    fn entries(dir: &Path) -> Vec<String> {
+        let mut entries: Vec<_> = std::fs::read_dir(dir).unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        entries.sort();
+        entries
+    }
+
+    #[test]
+    fn renders_every_variable() {
+        let vars = TemplateVars::new(7, 2023);
+
+        let rendered = vars.render("{{crate_name}}: https://adventofcode.com/{{year}}/day/{{day}}");
+
+        assert_eq!(rendered, "y2023_day_07: https://adventofcode.com/2023/day/7");
+    }
+
+    #[test]
+    fn appends_members_keeping_the_layout() {
+        let manifest = add_member(MANIFEST, "y2024/day_02").unwrap();
+
+        assert_eq!(manifest, "[workspace]\nmembers = [\n    \"xmas\",\n    \"y2024/day_01\",\n    \"y2024/day_02\",\n]\nresolver = \"2\"\n");
+    }
+
+    #[test]
+    fn moves_the_crate_into_place_on_commit() {
+        let root = workspace();
+
+        let staged = StagedDay::create(root.path(), Kind::Plain, &TemplateVars::new(2, 2024)).unwrap();
+        std::fs::write(staged.path().join("input.txt"), "1 2\n").unwrap();
+        let day_dir = staged.commit().unwrap();
+
+        let manifest = std::fs::read_to_string(day_dir.join("Cargo.toml")).unwrap();
+        assert_eq!(day_dir, root.path().join("y2024").join("day_02"));
+        assert_eq!(entries(&day_dir), ["Cargo.toml", "input.txt", "src"]);
+        assert_eq!(std::fs::read_to_string(day_dir.join("src").join("lib.rs")).unwrap(), "//! Plain day 2 of 2024\npub struct Puzzle;\n");
+        assert!(manifest.contains("name = \"y2024_day_02\""));
+        assert!(manifest.contains("path = \"../../xmas\""));
+        assert!(std::fs::read_to_string(root.path().join("Cargo.toml")).unwrap().contains("\"y2024/day_02\""));
+        assert!(day_exists(root.path(), "y2024/day_02").unwrap());
+    }
+
+    #[test]
+    fn leaves_nothing_behind_when_not_committed() {
+        let root = workspace();
+
+        let staged = StagedDay::create(root.path(), Kind::Plain, &TemplateVars::new(2, 2024)).unwrap();
+        drop(staged);
+
+        assert_eq!(entries(root.path()), ["Cargo.toml", TEMPLATES_DIR]);
+        assert!(!root.path().join("y2024").exists());
+        assert_eq!(std::fs::read_to_string(root.path().join("Cargo.toml")).unwrap(), MANIFEST);
+    }
+
+    #[test]
+    fn keeps_existing_year_dirs_when_rolling_back() {
+        let root = workspace();
+        std::fs::create_dir(root.path().join("y2024")).unwrap();
+
+        drop(StagedDay::create(root.path(), Kind::Plain, &TemplateVars::new(2, 2024)).unwrap());
+        assert!(StagedDay::create(root.path(), Kind::Vm, &TemplateVars::new(2, 2025)).is_err());
+
+        assert!(entries(&root.path().join("y2024")).is_empty());
+        assert!(!root.path().join("y2025").exists());
+    }
+
+    #[test]
+    fn refuses_existing_days() {
+        let root = workspace();
+
+        assert!(StagedDay::create(root.path(), Kind::Plain, &TemplateVars::new(1, 2024)).is_err());
+        std::fs::create_dir_all(root.path().join("y2024").join("day_03")).unwrap();
+        assert!(StagedDay::create(root.path(), Kind::Plain, &TemplateVars::new(3, 2024)).is_err());
+        assert!(StagedDay::create(root.path(), Kind::Plain, &TemplateVars::new(3, 2025)).is_ok());
+    }
+
+    #[test]
+    fn uses_the_template_of_the_kind() {
+        let root = workspace();
+
+        let day_dir = StagedDay::create(root.path(), Kind::Grid, &TemplateVars::new(4, 2024)).unwrap().commit().unwrap();
+
+        assert_eq!(std::fs::read_to_string(day_dir.join("src").join("lib.rs")).unwrap(), "//! Grid day 4 of 2024\npub struct Puzzle;\n");
+        assert!(StagedDay::create(root.path(), Kind::Vm, &TemplateVars::new(5, 2024)).is_err());
+    }
+
+    #[test]
+    fn finds_the_latest_year() {
+        let root = workspace();
+        assert_eq!(latest_year(root.path()).unwrap(), None);
+
+        for dir in ["y2023", "y2024", "yearly"] {
+            std::fs::create_dir(root.path().join(dir)).unwrap();
+        }
+        assert_eq!(latest_year(root.path()).unwrap(), Some(2024));
+    }
+}
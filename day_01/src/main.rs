@@ -1,28 +0,0 @@
-use anyhow::{self, Context};
-use day_01::{nums_diff, similarity_score};
-use xmas::display_result;
-
-fn main() -> anyhow::Result<()> {
-    part_1()?;
-    println!();
-    part_2()?;
-    Ok(())
-}
-
-fn part_1() -> anyhow::Result<()> {
-    println!("Part 1:");
-    let input = std::fs::read_to_string("./input.txt").context("Error reading input file.")?;
-
-    let result = nums_diff(&input)?;
-    display_result(&result);
-    Ok(())
-}
-
-fn part_2() -> anyhow::Result<()> {
-    println!("Part 2:");
-    let input = std::fs::read_to_string("./input.txt").context("Error reading input file.")?;
-
-    let result = similarity_score(&input)?;
-    display_result(&result);
-    Ok(())
-}
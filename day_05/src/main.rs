@@ -1,27 +0,0 @@
-use anyhow::{self, Context};
-use xmas::display_result;
-
-fn main() -> anyhow::Result<()> {
-    part_1()?;
-    println!();
-    part_2()?;
-    Ok(())
-}
-
-fn part_1() -> anyhow::Result<()> {
-    println!("Part 1:");
-    let input = std::fs::read_to_string("./input.txt").context("Error reading input file.")?;
-
-    let result = day_05::process_middle_page_sum(&input);
-    display_result(&result);
-    Ok(())
-}
-
-fn part_2() -> anyhow::Result<()> {
-    println!("Part 2:");
-    let input = std::fs::read_to_string("./input.txt").context("Error reading input file.")?;
-
-    let result = day_05::reordered_middle_page_sum(&input);
-    display_result(&result);
-    Ok(())
-}
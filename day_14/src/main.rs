@@ -1,26 +0,0 @@
-use anyhow::{self, Context};
-use xmas::{display_result, point2d::Point2D};
-
-fn main() -> anyhow::Result<()> {
-    part_1()?;
-    println!();
-    part_2()?;
-    Ok(())
-}
-
-fn part_1() -> anyhow::Result<()> {
-    println!("Part 1:");
-    let input = std::fs::read_to_string("./input.txt").context("Error reading input file.")?;
-
-    let result = day_14::calculate_safety_factor(&input, 100, Point2D(101, 103));
-    display_result(&result);
-    Ok(())
-}
-
-fn part_2() -> anyhow::Result<()> {
-    println!("Part 2:");
-    let input = std::fs::read_to_string("./input.txt").context("Error reading input file.")?;
-
-    day_14::find_christmas_tree_time(&input, Point2D(101, 103));
-    Ok(())
-}
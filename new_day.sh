@@ -1,2 +0,0 @@
-#!/bin/bash
-(set -a && . '.env' && set +a; cargo run --bin create_new_day $@)
\ No newline at end of file
@@ -0,0 +1,50 @@
+//! Advent of Code {{year}}, day {{day}}: <https://adventofcode.com/{{year}}/day/{{day}}>
+
+use std::str::FromStr;
+use anyhow::Context;
+use xmas::{map2d::ByteMap, point2d::Point2D, search::Search};
+use xmas::solution::Solution;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Parsed = ByteMap;
+    type Params = ();
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(ByteMap::from_str(input)?)
+    }
+
+    fn part_1(&self, map: &Self::Parsed, _: &()) -> anyhow::Result<u64> {
+        let start = map.find(&b'S').context("No start")?;
+        let end = map.find(&b'E').context("No end")?;
+        shortest_path(map, start, end).context("No path found")
+    }
+
+    fn part_2(&self, _map: &Self::Parsed, _: &()) -> anyhow::Result<u64> {
+        // Solve the puzzle here
+        Ok(0)
+    }
+}
+
+/// Steps of the shortest path between `start` and `end`, going through anything but walls.
+pub fn shortest_path(map: &ByteMap, start: Point2D, end: Point2D) -> Option<u64> {
+    Search::passable(map, |&tile| tile != b'#')
+        .bfs(start, |&point| point == end)
+        .path
+        .map(|path| path.cost)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_shortest_path() {
+        let map = Puzzle.parse("S.#\n#..\n#.E\n").unwrap();
+
+        assert_eq!(Puzzle.part_1(&map, &()).unwrap(), 4);
+    }
+}
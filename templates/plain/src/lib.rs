@@ -0,0 +1,36 @@
+//! Advent of Code {{year}}, day {{day}}: <https://adventofcode.com/{{year}}/day/{{day}}>
+
+use xmas::solution::Solution;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Parsed = String;
+    type Params = ();
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(input.to_string())
+    }
+
+    fn part_1(&self, _input: &Self::Parsed, _: &()) -> anyhow::Result<u64> {
+        // Solve the puzzle here
+        Ok(0)
+    }
+
+    fn part_2(&self, _input: &Self::Parsed, _: &()) -> anyhow::Result<u64> {
+        // Solve the puzzle here
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_the_input() {
+        assert!(Puzzle.parse("").is_ok());
+    }
+}
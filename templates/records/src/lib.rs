@@ -0,0 +1,60 @@
+//! Advent of Code {{year}}, day {{day}}: <https://adventofcode.com/{{year}}/day/{{day}}>
+
+use xmas::parse::{AocParse, FromInput, Input};
+use xmas::point2d::Point2D;
+use xmas::solution::Solution;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Parsed = Vec<Record>;
+    type Params = ();
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        let records = Input::new(input).lines()
+            .map(Record::from_input)
+            .collect::<Result<_, _>>()?;
+        Ok(records)
+    }
+
+    fn part_1(&self, _records: &Self::Parsed, _: &()) -> anyhow::Result<i64> {
+        // Solve the puzzle here
+        Ok(0)
+    }
+
+    fn part_2(&self, _records: &Self::Parsed, _: &()) -> anyhow::Result<i64> {
+        // Solve the puzzle here
+        Ok(0)
+    }
+}
+
+/// A line of the input, the format and the fields have to match it.
+#[derive(Debug, Clone, PartialEq, Eq, AocParse)]
+#[aoc(fmt = "p={position} v={velocity}")]
+pub struct Record {
+    pub position: Point2D,
+    pub velocity: Point2D,
+}
+
+#[cfg(test)]
+mod tests {
+    use xmas::parse::{ParseError, Span};
+
+    use super::*;
+
+    #[test]
+    fn parses_records() {
+        let records = Puzzle.parse("p=0,4 v=3,-3\n").unwrap();
+
+        assert_eq!(records, [Record { position: Point2D::new(0, 4), velocity: Point2D::new(3, -3) }]);
+    }
+
+    #[test]
+    fn reports_invalid_records() {
+        let err = Puzzle.parse("p=0,4 v=3,-3\np=1,x v=0,0").unwrap_err().downcast::<ParseError>().unwrap();
+
+        assert_eq!(err.span(), Span { line: 2, column: 5 });
+    }
+}
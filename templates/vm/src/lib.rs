@@ -0,0 +1,172 @@
+//! Advent of Code {{year}}, day {{day}}: <https://adventofcode.com/{{year}}/day/{{day}}>
+
+use std::str::FromStr;
+use thiserror::Error;
+use xmas::solution::Solution;
+use xmas::stepper::Simulation;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Parsed = Vec<Instruction>;
+    type Params = ();
+    type Answer1 = Value;
+    type Answer2 = Value;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        let program = input.lines()
+            .enumerate()
+            .map(|(i, l)| Instruction::from_str(l).map_err(|err| err.at_line(i + 1)))
+            .collect::<Result<_, _>>()?;
+        Ok(program)
+    }
+
+    fn part_1(&self, program: &Self::Parsed, _: &()) -> anyhow::Result<Value> {
+        let mut machine = Machine::new(program.clone());
+        machine.run();
+        Ok(machine.registers[0])
+    }
+
+    fn part_2(&self, _program: &Self::Parsed, _: &()) -> anyhow::Result<Value> {
+        // Solve the puzzle here
+        Ok(0)
+    }
+}
+
+pub type Value = i64;
+
+pub const REGISTERS: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    /// `nop`
+    Nop,
+    /// `add r v`: adds `v` to register `r`
+    Add(usize, Value),
+    /// `jmp o`: jumps `o` instructions
+    Jump(isize),
+    /// `out r`: outputs register `r`
+    Out(usize),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseError {
+    #[error("Unknown instruction `{instruction}` @ {line}:1")]
+    UnknownInstruction { instruction: String, line: usize },
+    #[error("Invalid argument `{argument}` @ {line}:{column}")]
+    InvalidArgument { argument: String, line: usize, column: usize },
+}
+
+impl ParseError {
+    /// Instructions are parsed line by line, this sets the line the error happened at.
+    fn at_line(self, line: usize) -> Self {
+        match self {
+            ParseError::UnknownInstruction { instruction, .. } => ParseError::UnknownInstruction { instruction, line },
+            ParseError::InvalidArgument { argument, column, .. } => ParseError::InvalidArgument { argument, line, column },
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut column = 1;
+        let words: Vec<(&str, usize)> = s.split(' ')
+            .map(|word| {
+                let word_at = (word, column);
+                column += word.len() + 1;
+                word_at
+            })
+            .filter(|(word, _)| !word.is_empty())
+            .collect();
+        let invalid = |&(word, column): &(&str, usize)| ParseError::InvalidArgument { argument: word.to_string(), line: 1, column };
+        let argument = |i: usize| words.get(i).copied().unwrap_or(("", column));
+        let value = |i: usize| argument(i).0.parse::<Value>().map_err(|_| invalid(&argument(i)));
+        let register = |i: usize| argument(i).0.parse::<usize>().ok()
+            .filter(|&register| register < REGISTERS)
+            .ok_or_else(|| invalid(&argument(i)));
+
+        match argument(0).0 {
+            "nop" => Ok(Instruction::Nop),
+            "add" => Ok(Instruction::Add(register(1)?, value(2)?)),
+            "jmp" => Ok(Instruction::Jump(value(1)? as isize)),
+            "out" => Ok(Instruction::Out(register(1)?)),
+            _ => Err(ParseError::UnknownInstruction { instruction: s.to_string(), line: 1 }),
+        }
+    }
+}
+
+/// Runs a program, it can also be watched with [`xmas::stepper::Stepper`].
+#[derive(Debug, Clone)]
+pub struct Machine {
+    pub program: Vec<Instruction>,
+    pub registers: [Value; REGISTERS],
+    pub ip: usize,
+    pub output: Vec<Value>,
+}
+
+impl Machine {
+    pub fn new(program: Vec<Instruction>) -> Self {
+        Self { program, registers: [0; REGISTERS], ip: 0, output: vec![] }
+    }
+
+    /// Runs until the program halts (jumps outside of it).
+    pub fn run(&mut self) {
+        while self.execute() {}
+    }
+
+    /// Executes the current instruction, returns `false` if the program had already halted.
+    pub fn execute(&mut self) -> bool {
+        let Some(&instruction) = self.program.get(self.ip) else {
+            return false;
+        };
+        match instruction {
+            Instruction::Nop => (),
+            Instruction::Add(register, value) => self.registers[register] += value,
+            Instruction::Jump(offset) => {
+                match self.ip.checked_add_signed(offset) {
+                    Some(ip) => self.ip = ip,
+                    None => self.ip = self.program.len(),
+                }
+                return true;
+            }
+            Instruction::Out(register) => self.output.push(self.registers[register]),
+        }
+        self.ip += 1;
+        true
+    }
+}
+
+impl Simulation for Machine {
+    fn step(&mut self) -> bool {
+        self.execute()
+    }
+
+    fn render(&self) -> String {
+        format!("ip: {} {:?}\nregisters: {:?}\noutput: {:?}", self.ip, self.program.get(self.ip), self.registers, self.output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_programs() {
+        let program = Puzzle.parse("add 0 2\nout 0\njmp 2\nadd 0 5\nadd 0 1").unwrap();
+        let mut machine = Machine::new(program);
+
+        machine.run();
+
+        assert_eq!((machine.registers[0], machine.output), (3, vec![2]));
+    }
+
+    #[test]
+    fn reports_invalid_instructions() {
+        assert_eq!(
+            Puzzle.parse("nop\nadd 9 1").unwrap_err().downcast::<ParseError>().unwrap(),
+            ParseError::InvalidArgument { argument: "9".to_string(), line: 2, column: 5 },
+        );
+    }
+}
@@ -0,0 +1,118 @@
+//! Ledger of the answers already accepted for the real input of a day.
+//!
+//! It lives next to the day's input as `answers.toml`:
+//!
+//! ```toml
+//! part_1 = 1530215
+//! part_2 = "6,1"
+//! ```
+//!
+//! The runner compares every new result against it, so a refactor that changes an
+//! answer is noticed instead of going by silently.
+
+use std::{fmt::Display, path::{Path, PathBuf}};
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::{fixture::Answer, solution::Part};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_1: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_2: Option<Answer>,
+}
+
+/// Outcome of comparing a result against the ledger.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Match,
+    Mismatch { expected: Answer },
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Match => write!(f, "match"),
+            Verdict::Mismatch { expected } => write!(f, "MISMATCH, expected {expected}"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl Answers {
+    pub fn path(day_dir: &Path) -> PathBuf {
+        day_dir.join("answers.toml")
+    }
+
+    /// Loads the ledger at `path`, a missing file is an empty ledger.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Error reading answers {}.", path.display()))?;
+        toml::from_str(&content)
+            .with_context(|| format!("Invalid answers file {}.", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(path, toml::to_string(self)?)
+            .with_context(|| format!("Error writing answers {}.", path.display()))
+    }
+
+    pub fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part_1.as_ref(),
+            Part::Two => self.part_2.as_ref(),
+        }
+    }
+
+    /// Records `result` as the accepted answer for `part`, stored as a number when possible.
+    pub fn set(&mut self, part: Part, result: &str) {
+        let answer = match result.parse() {
+            Ok(n) => Answer::Int(n),
+            Err(_) => Answer::Text(result.to_string()),
+        };
+        match part {
+            Part::One => self.part_1 = Some(answer),
+            Part::Two => self.part_2 = Some(answer),
+        }
+    }
+
+    pub fn check(&self, part: Part, result: &str) -> Verdict {
+        match self.get(part) {
+            Some(expected) if expected.to_string() == result => Verdict::Match,
+            Some(expected) => Verdict::Mismatch { expected: expected.clone() },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_results_against_recorded_answers() {
+        let answers: Answers = toml::from_str("part_1 = 22\n").unwrap();
+
+        assert_eq!(answers.check(Part::One, "22"), Verdict::Match);
+        assert_eq!(answers.check(Part::One, "23"), Verdict::Mismatch { expected: Answer::Int(22) });
+        assert_eq!(answers.check(Part::Two, "6,1"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn records_answers_round_trip() {
+        let mut answers = Answers::default();
+        answers.set(Part::One, "22");
+        answers.set(Part::Two, "6,1");
+
+        let content = toml::to_string(&answers).unwrap();
+
+        assert_eq!(content, "part_1 = 22\npart_2 = \"6,1\"\n");
+        assert_eq!(toml::from_str::<Answers>(&content).unwrap(), answers);
+    }
+}
@@ -1,3 +1,16 @@
+//! Directions on a grid and the axis conventions that turn them into points.
+//!
+//! Both conventions have `x` growing to the [`Right`]. They differ in `y`:
+//!
+//! - [`Axes::Screen`], the default, has `y` growing [`Down`], like the rows of the input.
+//!   [`crate::map2d::Map2D`] uses it unless told otherwise, with `(0, 0)` in the top left corner.
+//! - [`Axes::Math`] has `y` growing [`Up`], like the plots of a math book.
+//!
+//! The grids ([`Map2D`](crate::map2d::Map2D) and [`SparseMap2D`](crate::sparse_map2d::SparseMap2D))
+//! are given their axes with `with_axes`, which only changes how they are rendered: with the
+//! math ones the largest `y` is on top. [`Direction::as_point`] and the like use the screen
+//! axes, the `_in` versions take the axes of the grid, like `map.axes()`.
+
 use crate::point2d::Point2D;
 use enum_map::Enum;
 use Direction::*;
@@ -12,23 +25,59 @@ pub enum Direction {
 
 pub const DIRECTIONS: [Direction; 4] = [Up, Right, Down, Left];
 pub const DIRECTIONS_8: [Point2D; 8] = [
-    Point2D(0, -1),
-    Point2D(1, -1),
-    Point2D(1, 0),
-    Point2D(1, 1),
-    Point2D(0, 1),
-    Point2D(-1, 1),
-    Point2D(-1, 0),
-    Point2D(-1, -1),
+    Point2D::new(0, -1),
+    Point2D::new(1, -1),
+    Point2D::new(1, 0),
+    Point2D::new(1, 1),
+    Point2D::new(0, 1),
+    Point2D::new(-1, 1),
+    Point2D::new(-1, 0),
+    Point2D::new(-1, -1),
 ];
 
+/// Which way `y` grows, see the [module docs](self).
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
+pub enum Axes {
+    /// `y` grows downwards
+    #[default]
+    Screen,
+    /// `y` grows upwards
+    Math,
+}
+
+impl Axes {
+    /// Converts a point between these axes and the screen ones, both ways.
+    pub fn from_screen(&self, point: Point2D) -> Point2D {
+        match self {
+            Axes::Screen => point,
+            Axes::Math => Point2D::new(point.x(), -point.y()),
+        }
+    }
+}
+
 impl Direction {
     pub fn as_point(&self) -> Point2D {
-        match self {
-            Up => Point2D(0, -1),
-            Right => Point2D(1, 0),
-            Down => Point2D(0, 1),
-            Left => Point2D(-1, 0),
+        self.as_point_in(Axes::Screen)
+    }
+
+    pub fn as_point_in(&self, axes: Axes) -> Point2D {
+        let screen = match self {
+            Up => Point2D::new(0, -1),
+            Right => Point2D::new(1, 0),
+            Down => Point2D::new(0, 1),
+            Left => Point2D::new(-1, 0),
+        };
+        axes.from_screen(screen)
+    }
+
+    /// The direction of a point on an axis, and how far along it is.
+    pub fn from_point_in(point: Point2D, axes: Axes) -> Option<(Self, usize)> {
+        let distance = point.manhattan_magnitude();
+        match axes.from_screen(point).0 {
+            [0, 0] => None,
+            [0, y] => Some((if y > 0 { Down } else { Up }, distance)),
+            [x, 0] => Some((if x > 0 { Right } else { Left }, distance)),
+            _ => None,
         }
     }
 
@@ -36,6 +85,8 @@ impl Direction {
         self.as_point() + other.as_point()
     }
 
+    /// Turns clockwise for [`QuarterRotation::Right`], as seen on screen. With the math axes
+    /// that's still clockwise as plotted.
     pub fn turn(&self, rot: QuarterRotation) -> Self {
         let final_value = (self.value() + rot.value()) as usize % DIRECTIONS.len();
         DIRECTIONS[final_value]
@@ -61,6 +112,17 @@ impl From<Direction> for Point2D {
     }
 }
 
+/// Any point on an axis, with the screen axes.
+impl TryFrom<Point2D> for Direction {
+    type Error = &'static str;
+
+    fn try_from(value: Point2D) -> Result<Self, Self::Error> {
+        Direction::from_point_in(value, Axes::Screen)
+            .map(|(direction, _)| direction)
+            .ok_or("Cannot convert vector to direction")
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum QuarterRotation {
     None,
@@ -69,6 +131,13 @@ pub enum QuarterRotation {
     Left,
 }
 
+pub const QUARTER_ROTATIONS: [QuarterRotation; 4] = [
+    QuarterRotation::None,
+    QuarterRotation::Right,
+    QuarterRotation::TurnAround,
+    QuarterRotation::Left,
+];
+
 impl QuarterRotation {
     fn value(&self) -> u8 {
         match self {
@@ -78,4 +147,63 @@ impl QuarterRotation {
             Self::Left => 3,
         }
     }
+
+    pub fn inverse(&self) -> Self {
+        QUARTER_ROTATIONS[(4 - self.value() as usize) % 4]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(Axes::Screen, [Point2D::new(0, -1), Point2D::new(1, 0), Point2D::new(0, 1), Point2D::new(-1, 0)])]
+    #[case(Axes::Math, [Point2D::new(0, 1), Point2D::new(1, 0), Point2D::new(0, -1), Point2D::new(-1, 0)])]
+    fn follows_the_axes(#[case] axes: Axes, #[case] expected: [Point2D; 4]) {
+        assert_eq!(DIRECTIONS.map(|d| d.as_point_in(axes)), expected);
+    }
+
+    #[rstest]
+    fn points_round_trip(#[values(Axes::Screen, Axes::Math)] axes: Axes) {
+        for direction in DIRECTIONS {
+            let point = direction.as_point_in(axes);
+            for distance in 1..4 {
+                assert_eq!(Direction::from_point_in(point * distance as isize, axes), Some((direction, distance)));
+            }
+            assert_eq!(direction.inverse().as_point_in(axes), -point);
+        }
+        assert_eq!(Direction::from_point_in(Point2D::ZERO, axes), None);
+        assert_eq!(Direction::from_point_in(Point2D::new(1, 1), axes), None);
+    }
+
+    #[test]
+    fn converts_with_the_screen_axes() {
+        for direction in DIRECTIONS {
+            assert_eq!(Direction::try_from(direction.as_point()), Ok(direction));
+            assert_eq!(Point2D::from(direction), direction.as_point());
+        }
+        assert_eq!(Point2D::new(3, 0).try_get_direction(), Some((Right, 3)));
+        assert_eq!(Point2D::new(0, -2).try_get_direction(), Some((Up, 2)));
+        assert_eq!(Point2D::new(1, 1).try_get_direction_towards(Point2D::new(-1, 1)), Some((Left, 2)));
+    }
+
+    #[test]
+    fn turns_consistently() {
+        for direction in DIRECTIONS {
+            assert_eq!(direction.turn(QuarterRotation::None), direction);
+            assert_eq!(direction.turn(QuarterRotation::TurnAround), direction.inverse());
+            assert_eq!(direction.inverse().inverse(), direction);
+            // Clockwise on screen is (x, y) -> (-y, x)
+            let [x, y] = direction.as_point().0;
+            assert_eq!(direction.turn(QuarterRotation::Right).as_point(), Point2D::new(-y, x));
+            for rot in QUARTER_ROTATIONS {
+                assert_eq!(direction.turn(rot).turn(rot.inverse()), direction);
+                let full_turn = QUARTER_ROTATIONS.iter().fold(direction, |d, _| d.turn(rot));
+                assert_eq!(full_turn, direction, "4 turns of {rot:?} are a full turn");
+            }
+        }
+    }
 }
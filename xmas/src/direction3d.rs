@@ -1,4 +1,8 @@
-use crate::point3d::Point3D;
+//! Directions in 3D, following the [axes](crate::direction::Axes) of the 2D ones for `x`
+//! and `y`: [`Right`] is `+x`, and [`Down`] is `+y` with the screen axes or `-y` with the math
+//! ones. [`Front`] is `+z` and [`Back`] `-z` with both.
+
+use crate::{direction::{Axes, Direction, QuarterRotation}, point2d::Point2D, point3d::Point3D};
 use enum_map::Enum;
 use Direction3D::*;
 
@@ -17,13 +21,27 @@ pub const DIRECTIONS_3D: [Direction3D; 6] = [Up, Left, Down, Right, Front, Back]
 
 impl Direction3D {
     pub fn as_point(&self) -> Point3D {
-        match self {
-            Up => Point3D(0, -1, 0),
-            Left => Point3D(1, 0, 0),
-            Down => Point3D(0, 1, 0),
-            Right => Point3D(-1, 0, 0),
-            Front => Point3D(0, 0, 1),
-            Back => Point3D(0, 0, -1),
+        self.as_point_in(Axes::Screen)
+    }
+
+    pub fn as_point_in(&self, axes: Axes) -> Point3D {
+        let (xy, z) = match self {
+            Up => (Direction::Up.as_point_in(axes), 0),
+            Right => (Direction::Right.as_point_in(axes), 0),
+            Down => (Direction::Down.as_point_in(axes), 0),
+            Left => (Direction::Left.as_point_in(axes), 0),
+            Front => (Point2D::ZERO, 1),
+            Back => (Point2D::ZERO, -1),
+        };
+        Point3D::new(xy.x(), xy.y(), z)
+    }
+
+    /// The direction of a point on an axis.
+    pub fn from_point_in(point: Point3D, axes: Axes) -> Option<Self> {
+        match point.0 {
+            [0, 0, z] if z != 0 => Some(if z > 0 { Front } else { Back }),
+            [x, y, 0] => Direction::from_point_in(Point2D::new(x, y), axes).map(|(direction, _)| direction.into()),
+            _ => None,
         }
     }
 
@@ -41,6 +59,35 @@ impl Direction3D {
             Back => Front,
         }
     }
+
+    /// Turns around the `axis` pointing at the viewer, clockwise for
+    /// [`QuarterRotation::Right`]: around [`Back`] it's the same as turning a [`Direction`].
+    /// The directions along the axis don't move.
+    pub fn turn(&self, axis: Direction3D, rot: QuarterRotation) -> Self {
+        if *self == axis || *self == axis.inverse() {
+            return *self;
+        }
+        let point = self.as_point();
+        let clockwise = point.cross(axis.as_point());
+        let turned = match rot {
+            QuarterRotation::None => point,
+            QuarterRotation::Right => clockwise,
+            QuarterRotation::TurnAround => -point,
+            QuarterRotation::Left => -clockwise,
+        };
+        Self::from_point_in(turned, Axes::Screen).expect("turning a direction gives a direction")
+    }
+}
+
+impl From<Direction> for Direction3D {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::Up => Up,
+            Direction::Right => Right,
+            Direction::Down => Down,
+            Direction::Left => Left,
+        }
+    }
 }
 
 impl From<Direction3D> for Point3D {
@@ -49,15 +96,80 @@ impl From<Direction3D> for Point3D {
     }
 }
 
+/// Any point on an axis, with the screen axes.
 impl TryFrom<Point3D> for Direction3D {
     type Error = &'static str;
 
     fn try_from(value: Point3D) -> Result<Self, Self::Error> {
-        Ok(match value {
-            Point3D(x, 0, 0) if x != 0 => if x > 0 { Direction3D::Right } else { Direction3D::Left },
-            Point3D(0, y, 0) if y != 0 => if y > 0 { Direction3D::Up } else { Direction3D::Down },
-            Point3D(0, 0, z) if z != 0 => if z > 0 { Direction3D::Front } else { Direction3D::Back },
-            _ => return Err("Cannot convert vector to direction"),
-        })
+        Self::from_point_in(value, Axes::Screen)
+            .ok_or("Cannot convert vector to direction")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::direction::{DIRECTIONS, QUARTER_ROTATIONS};
+
+    #[test]
+    fn follows_the_screen_axes() {
+        assert_eq!(Right.as_point(), Point3D::new(1, 0, 0));
+        assert_eq!(Down.as_point(), Point3D::new(0, 1, 0));
+        assert_eq!(Up.as_point_in(Axes::Math), Point3D::new(0, 1, 0));
+        assert_eq!(Front.as_point_in(Axes::Math), Point3D::new(0, 0, 1));
+    }
+
+    #[rstest]
+    fn points_round_trip(#[values(Axes::Screen, Axes::Math)] axes: Axes) {
+        for direction in DIRECTIONS_3D {
+            let point = direction.as_point_in(axes);
+            assert_eq!(point.manhattan_magnitude(), 1);
+            assert_eq!(Direction3D::from_point_in(point * 3, axes), Some(direction));
+            assert_eq!(direction.inverse().as_point_in(axes), -point);
+            assert_eq!(direction.inverse().inverse(), direction);
+        }
+        assert_eq!(Direction3D::from_point_in(Point3D::ZERO, axes), None);
+        assert_eq!(Direction3D::from_point_in(Point3D::new(1, 0, 1), axes), None);
+    }
+
+    #[test]
+    fn converts_with_the_screen_axes() {
+        for direction in DIRECTIONS_3D {
+            assert_eq!(Direction3D::try_from(direction.as_point()), Ok(direction));
+        }
+        for direction in DIRECTIONS {
+            let point = direction.as_point();
+            assert_eq!(Direction3D::from(direction).as_point(), Point3D::new(point.x(), point.y(), 0));
+        }
+    }
+
+    #[test]
+    fn turns_like_2d_directions_around_the_viewer() {
+        for direction in DIRECTIONS {
+            for rot in QUARTER_ROTATIONS {
+                assert_eq!(Direction3D::from(direction).turn(Back, rot), direction.turn(rot).into());
+            }
+        }
+    }
+
+    #[test]
+    fn turns_consistently() {
+        for axis in DIRECTIONS_3D {
+            for direction in DIRECTIONS_3D {
+                let turned = direction.turn(axis, QuarterRotation::Right);
+                assert_eq!(turned.turn(axis, QuarterRotation::Left), direction);
+                assert_eq!(turned.turn(axis.inverse(), QuarterRotation::Right), direction);
+                assert_eq!(direction.turn(axis, QuarterRotation::TurnAround), match direction {
+                    d if d == axis || d == axis.inverse() => d,
+                    d => d.inverse(),
+                });
+                for rot in QUARTER_ROTATIONS {
+                    let full_turn = QUARTER_ROTATIONS.iter().fold(direction, |d, _| d.turn(axis, rot));
+                    assert_eq!(full_turn, direction, "4 turns of {rot:?} around {axis:?} are a full turn");
+                }
+            }
+        }
     }
 }
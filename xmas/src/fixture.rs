@@ -0,0 +1,192 @@
+//! Example inputs with their expected answers.
+//!
+//! Each example lives in a day's `examples` directory as a pair of files with the same name:
+//! `example_1.txt` holding the input and `example_1.toml` holding the expected answers:
+//!
+//! ```toml
+//! # Params shared by both parts, they override the day's default `Params`
+//! [params]
+//! map_size = [7, 7]
+//!
+//! [part_1]
+//! answer = 22
+//!
+//! [part_2]
+//! answer = "6,1"
+//! # Params only used for this part, they override the shared ones
+//! params = { bytes = 12 }
+//! ```
+//!
+//! Any of the parts can be omitted when the example doesn't have an answer for it, but not
+//! both: an example is only checked once its sidecar exists, so it's added when an answer
+//! is known. Keys the sidecar or the day's params don't have are an error, so a mistyped
+//! one can't go unnoticed.
+
+use std::{fmt::Display, path::{Path, PathBuf}};
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::solution::{DynSolution, Part};
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Fixture {
+    #[serde(default)]
+    pub params: toml::Table,
+    pub part_1: Option<Expected>,
+    pub part_2: Option<Expected>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    pub answer: Answer,
+    #[serde(default)]
+    pub params: toml::Table,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Int(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+impl Fixture {
+    /// Parses a sidecar, which must have the answer of at least one part.
+    pub fn parse(sidecar: &str) -> anyhow::Result<Self> {
+        let fixture: Self = toml::from_str(sidecar)?;
+        if fixture.part_1.is_none() && fixture.part_2.is_none() {
+            anyhow::bail!("There is no answer for any part, add a `[part_1]` or `[part_2]` table.");
+        }
+        Ok(fixture)
+    }
+
+    pub fn expected(&self, part: Part) -> Option<&Expected> {
+        match part {
+            Part::One => self.part_1.as_ref(),
+            Part::Two => self.part_2.as_ref(),
+        }
+    }
+
+    /// Shared params merged with the ones specific to `part`.
+    pub fn params_for(&self, part: Part) -> toml::Table {
+        let mut params = self.params.clone();
+        if let Some(expected) = self.expected(part) {
+            params.extend(expected.params.clone());
+        }
+        params
+    }
+}
+
+/// An example input together with its expected answers.
+#[derive(Debug, Clone)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub fixture: Fixture,
+}
+
+impl Example {
+    /// Loads an example from its `.toml` sidecar, the input is the `.txt` file next to it.
+    pub fn load(sidecar_path: &Path) -> anyhow::Result<Self> {
+        let name = sidecar_path.file_stem()
+            .context("Example sidecar has no file name")?
+            .to_string_lossy()
+            .to_string();
+        let sidecar = std::fs::read_to_string(sidecar_path)
+            .with_context(|| format!("Error reading example sidecar {}.", sidecar_path.display()))?;
+        let fixture = Fixture::parse(&sidecar)
+            .with_context(|| format!("Invalid example sidecar {}.", sidecar_path.display()))?;
+
+        let input_path = sidecar_path.with_extension("txt");
+        let input = std::fs::read_to_string(&input_path)
+            .with_context(|| format!("Error reading example input {}.", input_path.display()))?;
+
+        Ok(Self { name, input, fixture })
+    }
+
+    /// Loads every example found in `dir`, sorted by name.
+    pub fn load_all(dir: &Path) -> anyhow::Result<Vec<Self>> {
+        if !dir.is_dir() {
+            return Ok(vec![]);
+        }
+
+        let mut sidecars: Vec<PathBuf> = std::fs::read_dir(dir)?
+            .map(|entry| entry.map(|e| e.path()))
+            .collect::<Result<_, _>>()?;
+        sidecars.retain(|p| p.extension().is_some_and(|ext| ext == "toml"));
+        sidecars.sort();
+
+        sidecars.iter()
+            .map(|p| Self::load(p))
+            .collect()
+    }
+
+    /// Solves every part this example has an answer for, returns an error listing the
+    /// parts whose answer didn't match.
+    pub fn check(&self, solution: &dyn DynSolution) -> anyhow::Result<()> {
+        let mut mismatches = vec![];
+        for part in Part::ALL {
+            let expected = match self.fixture.expected(part) {
+                Some(expected) => expected.answer.to_string(),
+                None => continue,
+            };
+            let params = self.fixture.params_for(part);
+            let result = solution.solve_with_params(&self.input, part, &params)
+                .with_context(|| format!("{} failed on example {}", part, self.name))?;
+            if result != expected {
+                mismatches.push(format!("{part}: expected {expected} but got {result}"));
+            }
+        }
+
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!("Example {} doesn't match:\n{}", self.name, mismatches.join("\n")))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_params_override_shared_ones() {
+        let fixture: Fixture = toml::from_str(concat!(
+            "[params]\n",
+            "size = 7\n",
+            "bytes = 12\n",
+            "[part_1]\n",
+            "answer = 22\n",
+            "[part_2]\n",
+            "answer = \"6,1\"\n",
+            "params = { bytes = 20 }\n",
+        )).unwrap();
+
+        assert_eq!(fixture.params_for(Part::One)["bytes"], toml::Value::Integer(12));
+        assert_eq!(fixture.params_for(Part::Two)["bytes"], toml::Value::Integer(20));
+        assert_eq!(fixture.params_for(Part::Two)["size"], toml::Value::Integer(7));
+        assert_eq!(fixture.part_1.unwrap().answer.to_string(), "22");
+        assert_eq!(fixture.part_2.unwrap().answer.to_string(), "6,1");
+    }
+
+    #[test]
+    fn rejects_sidecars_that_check_nothing() {
+        assert!(Fixture::parse("[part1]\nanswer = 22\n").is_err());
+        assert!(Fixture::parse("[part_1]\nanswers = 22\n").is_err());
+        assert!(Fixture::parse("[params]\nsize = 7\n").is_err());
+        assert!(Fixture::parse("").is_err());
+        assert!(Fixture::parse("[part_2]\nanswer = 6\n").is_ok());
+    }
+}
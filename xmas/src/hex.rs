@@ -0,0 +1,445 @@
+//! Hexagonal grids, with axial coordinates: `q` grows to the east, `r` to the south east and
+//! the implicit cube one `s = -q - r` to the north west.
+//!
+//! The hexagons are pointy top, so the neighbours are to the [`East`], [`SouthEast`],
+//! [`SouthWest`], [`West`], [`NorthWest`] and [`NorthEast`]. Flat top grids, with north and
+//! south neighbours, are the same grid turned, see [`HexDirection::from_flat_top`].
+//!
+//! ```
+//! use xmas::hex::{Hex, HexDirection};
+//! use xmas::parse::Input;
+//!
+//! let path = HexDirection::parse_path(Input::new("esenee")).unwrap();
+//! let end: Hex = path.iter().map(|d| d.as_hex()).sum();
+//!
+//! assert_eq!(end, Hex::new(3, 0));
+//! assert_eq!(end.distance(Hex::ZERO), 3);
+//! ```
+
+use std::{fmt::Display, iter::Sum, ops, str::FromStr};
+use thiserror::Error;
+
+use crate::{map2d::Map2D, parse::{FromInput, Input, ParseError}, point2d::Point2D, point3d::Point3D, vector::Vector};
+use HexDirection::*;
+
+/// Axial coordinates, with the arithmetic of the [`Vector`] they wrap.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Hash)]
+pub struct Hex(pub Vector<isize, 2>);
+
+impl Hex {
+    pub const ZERO: Self = Hex::new(0, 0);
+
+    pub const fn new(q: isize, r: isize) -> Self {
+        Self(Vector::<isize, 2>::new(q, r))
+    }
+
+    pub const fn q(&self) -> isize {
+        self.0.x()
+    }
+
+    pub const fn r(&self) -> isize {
+        self.0.y()
+    }
+
+    /// From cube coordinates, which must add up to 0.
+    pub fn from_cube(cube: Point3D) -> Option<Self> {
+        let [q, r, s] = cube.0;
+        (q + r + s == 0).then_some(Self::new(q, r))
+    }
+
+    pub fn s(&self) -> isize {
+        -self.q() - self.r()
+    }
+
+    pub fn cube(&self) -> Point3D {
+        Point3D::new(self.q(), self.r(), self.s())
+    }
+
+    /// Steps to the origin.
+    pub fn magnitude(&self) -> usize {
+        self.cube().manhattan_magnitude() / 2
+    }
+
+    /// Steps to `other`.
+    pub fn distance(&self, other: Hex) -> usize {
+        (*self - other).magnitude()
+    }
+
+    pub fn neighbours(&self) -> impl Iterator<Item = Hex> + '_ {
+        HEX_DIRECTIONS.iter().map(move |d| *self + d.as_hex())
+    }
+
+    /// Rotates around the origin by `sixths` of a turn, clockwise when positive.
+    pub fn rotate(&self, sixths: isize) -> Self {
+        let mut cube = self.cube();
+        for _ in 0..sixths.rem_euclid(6) {
+            let [q, r, s] = cube.0;
+            cube = Point3D::new(-r, -s, -q);
+        }
+        Self::new(cube.x(), cube.y())
+    }
+
+    /// The hexes at `radius` steps from `self`, going clockwise from the west.
+    pub fn ring(&self, radius: usize) -> impl Iterator<Item = Hex> {
+        let start = *self + West.as_hex() * radius as isize;
+        // From the west corner the sides go north east, east, and so on
+        let steps = (0..HEX_DIRECTIONS.len())
+            .flat_map(move |side| std::iter::repeat_n(HEX_DIRECTIONS[(side + 5) % 6], radius));
+        std::iter::once(start)
+            .chain(steps.scan(start, |hex, direction| {
+                *hex += direction.as_hex();
+                Some(*hex)
+            }))
+            .take((6 * radius).max(1))
+    }
+
+    /// `self` and the rings around it up to `radius`, from the inside out.
+    pub fn spiral(&self, radius: usize) -> impl Iterator<Item = Hex> {
+        let center = *self;
+        (0..=radius).flat_map(move |r| center.ring(r))
+    }
+}
+
+impl Display for Hex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// The operators of the wrapped vector, by a hex, with their assigning versions.
+macro_rules! vector_ops {
+    ($($op:ident $method:ident $op_assign:ident $method_assign:ident),*) => {
+        $(
+            impl ops::$op for Hex {
+                type Output = Self;
+
+                fn $method(self, rhs: Self) -> Self::Output {
+                    Self(ops::$op::$method(self.0, rhs.0))
+                }
+            }
+
+            impl ops::$op_assign for Hex {
+                fn $method_assign(&mut self, rhs: Self) {
+                    ops::$op_assign::$method_assign(&mut self.0, rhs.0)
+                }
+            }
+        )*
+    };
+}
+
+vector_ops!(
+    Add add AddAssign add_assign,
+    Sub sub SubAssign sub_assign
+);
+
+impl ops::Mul<isize> for Hex {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl ops::Neg for Hex {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl Sum for Hex {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|hex| hex.0).sum())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum HexDirection {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+/// Clockwise from the east.
+pub const HEX_DIRECTIONS: [HexDirection; 6] = [East, SouthEast, SouthWest, West, NorthWest, NorthEast];
+
+impl HexDirection {
+    pub fn as_hex(&self) -> Hex {
+        match self {
+            East => Hex::new(1, 0),
+            SouthEast => Hex::new(0, 1),
+            SouthWest => Hex::new(-1, 1),
+            West => Hex::new(-1, 0),
+            NorthWest => Hex::new(0, -1),
+            NorthEast => Hex::new(1, -1),
+        }
+    }
+
+    /// Turns by `sixths` of a turn, clockwise when positive.
+    pub fn turn(&self, sixths: isize) -> Self {
+        HEX_DIRECTIONS[(self.value() as isize + sixths).rem_euclid(6) as usize]
+    }
+
+    pub fn inverse(&self) -> Self {
+        self.turn(3)
+    }
+
+    fn value(&self) -> usize {
+        match self {
+            East => 0,
+            SouthEast => 1,
+            SouthWest => 2,
+            West => 3,
+            NorthWest => 4,
+            NorthEast => 5,
+        }
+    }
+
+    /// The direction of a flat top grid (`n`, `ne`, `se`, `s`, `sw` or `nw`), on the pointy
+    /// top one it is turned to: a 30° turn counterclockwise, so `n` is [`NorthWest`].
+    pub fn from_flat_top(s: &str) -> Result<Self, ParseHexDirectionError> {
+        Ok(match s {
+            "n" => NorthWest,
+            "ne" => NorthEast,
+            "se" => East,
+            "s" => SouthEast,
+            "sw" => SouthWest,
+            "nw" => West,
+            _ => return Err(ParseHexDirectionError::Unknown(s.to_string())),
+        })
+    }
+
+    /// Directions one after the other, `esenee`, or separated by commas or spaces, `ne,se`.
+    pub fn parse_path(input: Input) -> Result<Vec<Self>, ParseError> {
+        let text = input.as_str().trim_end();
+        let mut path = vec![];
+        let mut i = 0;
+        while i < text.len() {
+            let rest = &text[i..];
+            let first = rest.chars().next().expect("not at the end");
+            if first == ',' || first.is_whitespace() {
+                i += first.len_utf8();
+                continue;
+            }
+            let chars = if matches!(first, 'n' | 's') { 2 } else { 1 };
+            let end = i + rest.char_indices().nth(chars).map_or(rest.len(), |(len, _)| len);
+            path.push(Self::from_input(input.slice(i..end))?);
+            i = end;
+        }
+        Ok(path)
+    }
+}
+
+impl From<HexDirection> for Hex {
+    fn from(value: HexDirection) -> Self {
+        value.as_hex()
+    }
+}
+
+impl Display for HexDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            East => "e",
+            SouthEast => "se",
+            SouthWest => "sw",
+            West => "w",
+            NorthWest => "nw",
+            NorthEast => "ne",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// `e`, `se`, `sw`, `w`, `nw` or `ne`.
+impl FromStr for HexDirection {
+    type Err = ParseHexDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        HEX_DIRECTIONS.into_iter()
+            .find(|d| d.to_string() == s)
+            .ok_or_else(|| ParseHexDirectionError::Unknown(s.to_string()))
+    }
+}
+
+impl FromInput for HexDirection {
+    fn from_input(input: Input<'_>) -> Result<Self, ParseError> {
+        input.parse()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseHexDirectionError {
+    #[error("Unknown hex direction `{0}`")]
+    Unknown(String),
+}
+
+/// Stores the tiles of a parallelogram of hexes in a [`Map2D`], `q` being the column and `r`
+/// the row.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HexMap<T> {
+    map: Map2D<T>,
+    /// Hex of the first tile of the map
+    origin: Hex,
+}
+
+impl<T: Clone> HexMap<T> {
+    /// All the hexes from `min` to `max` included, both for `q` and `r`.
+    pub fn new_filled(min: Hex, max: Hex, tile: T) -> Self {
+        let size = ((max - min).0 + Point2D::new(1, 1)).max(Point2D::ZERO);
+        Self { map: Map2D::new_filled(size, tile), origin: min }
+    }
+
+    /// Enough room for the hexes up to `radius` steps away from the origin.
+    pub fn hexagon(radius: usize, tile: T) -> Self {
+        let radius = radius as isize;
+        Self::new_filled(Hex::new(-radius, -radius), Hex::new(radius, radius), tile)
+    }
+}
+
+impl<T> HexMap<T> {
+    /// Uses the columns of `map` as `q` and its rows as `r`, starting at `origin`.
+    pub fn from_map(map: Map2D<T>, origin: Hex) -> Self {
+        Self { map, origin }
+    }
+
+    fn to_point(&self, hex: Hex) -> Point2D {
+        (hex - self.origin).0
+    }
+
+    fn to_hex(&self, point: Point2D) -> Hex {
+        Hex(point) + self.origin
+    }
+
+    pub fn is_inside(&self, hex: Hex) -> bool {
+        self.map.is_inside(self.to_point(hex))
+    }
+
+    pub fn get_tile(&self, hex: Hex) -> Option<&T> {
+        self.map.get_tile(self.to_point(hex))
+    }
+
+    pub fn get_tile_mut(&mut self, hex: Hex) -> Option<&mut T> {
+        let point = self.to_point(hex);
+        self.map.get_tile_mut(point)
+    }
+
+    pub fn set_tile(&mut self, hex: Hex, tile: T) -> bool {
+        let point = self.to_point(hex);
+        self.map.set_tile(point, tile)
+    }
+
+    pub fn iter_with_hexes(&self) -> impl Iterator<Item = (Hex, &T)> + '_ {
+        self.map.iter_with_points().map(|(point, tile)| (self.to_hex(point), tile))
+    }
+
+    pub fn map(&self) -> &Map2D<T> {
+        &self.map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn measures_distances() {
+        assert_eq!(Hex::new(3, -1).magnitude(), 3);
+        assert_eq!(Hex::new(1, 2).distance(Hex::new(-1, 0)), 4);
+        assert_eq!(Hex::from_cube(Hex::new(2, -5).cube()), Some(Hex::new(2, -5)));
+        assert_eq!(Hex::from_cube(Point3D::new(1, 1, 1)), None);
+        for direction in HEX_DIRECTIONS {
+            assert_eq!(direction.as_hex().magnitude(), 1);
+        }
+    }
+
+    #[test]
+    fn turns() {
+        for direction in HEX_DIRECTIONS {
+            assert_eq!(direction.turn(6), direction);
+            assert_eq!(direction.turn(-1), direction.turn(5));
+            assert_eq!(direction.inverse().as_hex(), -direction.as_hex());
+            assert_eq!(direction.as_hex().rotate(1), direction.turn(1).as_hex());
+            assert_eq!(direction.as_hex().rotate(-2), direction.turn(-2).as_hex());
+        }
+    }
+
+    #[rstest]
+    #[case(0, 1)]
+    #[case(1, 6)]
+    #[case(3, 18)]
+    fn walks_rings(#[case] radius: usize, #[case] count: usize) {
+        let center = Hex::new(2, -1);
+
+        let ring: Vec<_> = center.ring(radius).collect();
+
+        assert_eq!(ring.len(), count);
+        assert!(ring.iter().all(|hex| hex.distance(center) == radius));
+        assert_eq!(ring.iter().collect::<std::collections::HashSet<_>>().len(), count);
+    }
+
+    #[test]
+    fn walks_spirals() {
+        let spiral: Vec<_> = Hex::ZERO.spiral(2).collect();
+
+        assert_eq!(spiral.len(), 1 + 6 + 12);
+        assert_eq!(spiral[0], Hex::ZERO);
+        assert!(spiral.windows(2).all(|w| w[0].magnitude() <= w[1].magnitude()));
+    }
+
+    #[rstest]
+    #[case("nwwswee", Hex::ZERO)]
+    #[case("esew", Hex::new(0, 1))]
+    #[case("ne, e,w", Hex::new(1, -1))]
+    fn parses_paths(#[case] s: &str, #[case] end: Hex) {
+        let path = HexDirection::parse_path(Input::new(s)).unwrap();
+
+        assert_eq!(path.into_iter().map(Hex::from).sum::<Hex>(), end);
+    }
+
+    #[rstest]
+    #[case("ne,ne,ne", 3)]
+    #[case("ne,ne,sw,sw", 0)]
+    #[case("ne,ne,s,s", 2)]
+    #[case("se,sw,se,sw,sw", 3)]
+    fn parses_flat_top_directions(#[case] s: &str, #[case] distance: usize) {
+        let end: Hex = s.split(',')
+            .map(|d| HexDirection::from_flat_top(d).unwrap().as_hex())
+            .sum();
+
+        assert_eq!(end.magnitude(), distance);
+    }
+
+    #[test]
+    fn reports_unknown_directions() {
+        assert_eq!(
+            HexDirection::parse_path(Input::new("ee\nex")).unwrap_err(),
+            ParseError::Invalid {
+                expected: "HexDirection".to_string(),
+                found: "x".to_string(),
+                span: crate::parse::Span { line: 2, column: 2 },
+                reason: "Unknown hex direction `x`".to_string(),
+            },
+        );
+    }
+
+    #[test]
+    fn stores_hexes_in_a_map() {
+        let mut map = HexMap::hexagon(2, 0);
+
+        for hex in Hex::ZERO.spiral(2) {
+            assert!(map.set_tile(hex, hex.magnitude()));
+        }
+
+        assert_eq!(map.get_tile(Hex::new(-2, 2)), Some(&2));
+        assert_eq!(map.get_tile(Hex::new(-2, -2)), Some(&0));
+        assert!(!map.is_inside(Hex::new(3, 0)));
+        assert_eq!(map.iter_with_hexes().filter(|(_, &tile)| tile == 1).count(), 6);
+        assert_eq!(map.map().size(), Point2D::new(5, 5));
+    }
+}
@@ -1,11 +1,27 @@
+// Lets `#[derive(AocParse)]` refer to `::xmas` from inside the crate too.
+extern crate self as xmas;
+
+pub mod answers;
 pub mod direction;
 pub mod direction3d;
+pub mod fixture;
+pub mod hex;
 pub mod map2d;
+pub mod map3d;
+pub mod parse;
 pub mod point2d;
 pub mod point3d;
 pub mod result;
+pub mod rotation3d;
+pub mod search;
+pub mod sparse_map2d;
+pub mod solution;
+pub mod stepper;
+pub mod vector;
+pub mod visualize;
 pub mod keyed_ord;
 pub mod num;
 
 pub use result::display_result;
 pub use num::*;
+pub use sparse_map2d::SparseMap2D;
@@ -2,35 +2,40 @@ use core::str;
 use std::{convert::Infallible, fmt::Display, str::FromStr};
 use thiserror::Error;
 
-use crate::point2d::Point2D;
+use crate::{direction::Axes, point2d::Point2D};
 
 pub type ByteMap = Map2D<u8>;
 pub type CharMap = Map2D<char>;
 
+/// A grid of tiles stored row by row, `(0, 0)` being the first tile of the first row.
+///
+/// The [`Axes`] only matter when rendering it: with the math ones the last row is on top.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Map2D<Tile = u8> {
     map: Vec<Tile>,
     width: usize,
     height: usize,
+    axes: Axes,
 }
 
 impl<T: Clone> Map2D<T> {
     pub fn new_filled(size: Point2D, tile: T) -> Self {
-        let width = size.0 as usize;
-        let height = size.1 as usize;
+        let width = size.x() as usize;
+        let height = size.y() as usize;
         let map = vec![tile; width * height];
         Self {
             map,
             width,
             height,
+            axes: Axes::default(),
         }
     }
 }
 
 impl<T: Default> Map2D<T> {
     pub fn new_with_default_tiles(size: Point2D) -> Self {
-        let width = size.0 as usize;
-        let height = size.1 as usize;
+        let width = size.x() as usize;
+        let height = size.y() as usize;
         let mut map = Vec::with_capacity(width * height);
         for _ in 0..(width * height) {
             map.push(T::default());
@@ -39,11 +44,20 @@ impl<T: Default> Map2D<T> {
             map,
             width,
             height,
+            axes: Axes::default(),
         }
     }
 }
 
 impl<T> Map2D<T> {
+    pub fn with_axes(self, axes: Axes) -> Self {
+        Self { axes, ..self }
+    }
+
+    pub fn axes(&self) -> Axes {
+        self.axes
+    }
+
     pub fn from_str_with_parser<'a, Iter, Parser>(
         s: &'a str,
         parser: &mut Parser,
@@ -60,7 +74,7 @@ impl<T> Map2D<T> {
         
         let first_line = lines.next().unwrap();
 
-        let mut map = Self { map, width: 0, height: 0 };
+        let mut map = Self { map, width: 0, height: 0, axes: Axes::default() };
         map.parse_and_add_row(first_line, parser)?;
         for line in lines {
             map.parse_and_add_row(line, parser)?;
@@ -69,6 +83,15 @@ impl<T> Map2D<T> {
         Ok(map)
     }
 
+    /// Builds a map from its rows, which must all have the same width.
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Result<Self, ParseMapError> {
+        let mut map = Self { map: vec![], width: 0, height: 0, axes: Axes::default() };
+        for row in rows {
+            map.add_row(row.into_iter())?;
+        }
+        Ok(map)
+    }
+
     pub fn parse_and_add_row<'a, Iter, Parser>(
         &mut self,
         line: &'a str,
@@ -87,13 +110,13 @@ impl<T> Map2D<T> {
         } else if tiles.len() != self.width {
             return Err(ParseMapError::InconsistentRowSize { current: tiles.len(), expected: self.width });
         }
-        self.map.extend(tiles.into_iter());
+        self.map.extend(tiles);
         self.height += 1;
         Ok(())
     }
 
     pub fn is_inside(&self, point: Point2D) -> bool {
-        point.0 >= 0 && point.1 >= 0 && (point.0 as usize) < self.width && (point.1 as usize) < self.height
+        point.x() >= 0 && point.y() >= 0 && (point.x() as usize) < self.width && (point.y() as usize) < self.height
     }
 
     pub fn set_tile(&mut self, point: Point2D, tile: T) -> bool {
@@ -114,12 +137,12 @@ impl<T> Map2D<T> {
     }
 
     pub fn get_index(&self, point: Point2D) -> Option<usize> {
-        self.is_inside(point).then(|| point.0 as usize + (point.1 as usize * self.width))
+        self.is_inside(point).then(|| point.x() as usize + (point.y() as usize * self.width))
     }
 
     pub fn iter_points(&self) -> impl Iterator<Item = Point2D> + '_ {
         (0..(self.height as isize))
-            .flat_map(|y| (0..(self.width as isize)).map(move |x| Point2D(x, y)))
+            .flat_map(|y| (0..(self.width as isize)).map(move |x| Point2D::new(x, y)))
     }
 
     pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
@@ -128,7 +151,7 @@ impl<T> Map2D<T> {
 
     pub fn iter_with_points(&self) -> impl Iterator<Item = (Point2D, &T)> + '_ {
         (0..(self.height as isize))
-            .flat_map(|y| (0..(self.width as isize)).map(move |x| Point2D(x, y)))
+            .flat_map(|y| (0..(self.width as isize)).map(move |x| Point2D::new(x, y)))
             .map(|p| (p, self.get_tile(p).unwrap()))
     }
 
@@ -138,6 +161,16 @@ impl<T> Map2D<T> {
         &self.map[start..end]
     }
 
+    /// The rows with their `y` in the order they are rendered, top one first: the last row
+    /// goes first with the math [`Axes`].
+    pub fn rows_top_down(&self) -> impl Iterator<Item = (isize, &[T])> {
+        let mut rows: Vec<_> = self.rows_iter().enumerate().map(|(y, row)| (y as isize, row)).collect();
+        if self.axes == Axes::Math {
+            rows.reverse();
+        }
+        rows.into_iter()
+    }
+
     pub fn rows_iter(&self) -> impl Iterator<Item = &[T]> {
         (0..self.height).map(|y| self.row(y))
     }
@@ -151,7 +184,17 @@ impl<T> Map2D<T> {
     }
 
     pub fn size(&self) -> Point2D {
-        Point2D(self.width as isize, self.height as isize)
+        Point2D::new(self.width as isize, self.height as isize)
+    }
+}
+
+/// The tiles row by row, like [`Map2D::iter`].
+impl<T> IntoIterator for Map2D<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.into_iter()
     }
 }
 
@@ -183,7 +226,7 @@ impl FromStr for Map2D<u8> {
 
 impl Display for Map2D<u8> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for line in self.rows_iter() {
+        for (_, line) in self.rows_top_down() {
             writeln!(f, "{}", String::from_utf8_lossy(line))?;
         }
         Ok(())
@@ -200,7 +243,7 @@ impl FromStr for Map2D<char> {
 
 impl Display for Map2D<char> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for line in self.rows_iter() {
+        for (_, line) in self.rows_top_down() {
             writeln!(f, "{}", line.iter().collect::<String>())?;
         }
         Ok(())
@@ -214,7 +257,7 @@ mod tests {
 
     #[test]
     fn builds_map_correctly() {
-        let map = ByteMap::new_with_default_tiles(Point2D(20, 10));
+        let map = ByteMap::new_with_default_tiles(Point2D::new(20, 10));
 
         assert_eq!(map.width, 20);
         assert_eq!(map.height, 10);
@@ -222,12 +265,12 @@ mod tests {
     }
 
     #[rstest]
-    #[case(Point2D(20, 10), Point2D(0, 0), Some(0))]
-    #[case(Point2D(20, 10), Point2D(4, 5), Some(104))]
-    #[case(Point2D(20, 10), Point2D(-1, 0), None)]
-    #[case(Point2D(20, 10), Point2D(0, -1), None)]
-    #[case(Point2D(20, 10), Point2D(20, 0), None)]
-    #[case(Point2D(20, 10), Point2D(0, 10), None)]
+    #[case(Point2D::new(20, 10), Point2D::new(0, 0), Some(0))]
+    #[case(Point2D::new(20, 10), Point2D::new(4, 5), Some(104))]
+    #[case(Point2D::new(20, 10), Point2D::new(-1, 0), None)]
+    #[case(Point2D::new(20, 10), Point2D::new(0, -1), None)]
+    #[case(Point2D::new(20, 10), Point2D::new(20, 0), None)]
+    #[case(Point2D::new(20, 10), Point2D::new(0, 10), None)]
     fn index_is_equal_to_expected(
         #[case] map_size: Point2D,
         #[case] point: Point2D,
@@ -252,6 +295,15 @@ mod tests {
         assert_eq!(map.height, 3);
     }
 
+    #[test]
+    fn renders_the_math_axes_upside_down() {
+        let map = CharMap::from_str("#.\n.@\n").unwrap().with_axes(Axes::Math);
+
+        assert_eq!(map.get_tile(Point2D::new(1, 1)), Some(&'@'));
+        assert_eq!(map.to_string(), ".@\n#.\n");
+        assert_eq!(map.rows_top_down().map(|(y, _)| y).collect::<Vec<_>>(), [1, 0]);
+    }
+
     #[test]
     fn parse_map_returns_empty_error() {
         let result = ByteMap::from_str("");
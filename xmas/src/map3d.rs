@@ -0,0 +1,313 @@
+use std::{collections::{HashSet, VecDeque}, str::FromStr};
+use thiserror::Error;
+
+use crate::{direction3d::DIRECTIONS_3D, map2d::Map2D, point3d::{ParsePoint3DError, Point3D}};
+
+/// A grid of voxels, like [`Map2D`] with a `z` for the layers. `(0, 0, 0)` is the first
+/// tile, the others are stored row by row and then layer by layer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Map3D<Tile = u8> {
+    map: Vec<Tile>,
+    width: usize,
+    height: usize,
+    depth: usize,
+}
+
+impl<T: Clone> Map3D<T> {
+    /// A map of `size` with every tile set to `tile`. Panics if a component of `size` is
+    /// negative.
+    pub fn new_filled(size: Point3D, tile: T) -> Self {
+        let [width, height, depth] = dimensions(size);
+        let map = vec![tile; width * height * depth];
+        Self {
+            map,
+            width,
+            height,
+            depth,
+        }
+    }
+
+    /// The tiles at `z`, with the same `x` and `y` as here, or `None` if `z` is outside the map.
+    pub fn layer(&self, z: usize) -> Option<Map2D<T>> {
+        if z >= self.depth {
+            return None;
+        }
+        let start = z * self.width * self.height;
+        let end = start + self.width * self.height;
+        let rows = self.map[start..end].chunks(self.width.max(1)).map(<[T]>::to_vec);
+        Some(Map2D::from_rows(rows).expect("every row of a layer has the same width"))
+    }
+
+    pub fn layers_iter(&self) -> impl Iterator<Item = Map2D<T>> + '_ {
+        (0..self.depth).map_while(|z| self.layer(z))
+    }
+}
+
+impl<T: Default> Map3D<T> {
+    /// Like [`new_filled`](Map3D::new_filled), with the default tile.
+    pub fn new_with_default_tiles(size: Point3D) -> Self {
+        let [width, height, depth] = dimensions(size);
+        let map = (0..(width * height * depth)).map(|_| T::default()).collect();
+        Self {
+            map,
+            width,
+            height,
+            depth,
+        }
+    }
+}
+
+/// Width, height and depth of a map of `size`.
+fn dimensions(size: Point3D) -> [usize; 3] {
+    assert!(size.0.iter().all(|&c| c >= 0), "the size of a map can't be negative: {size}");
+    size.0.map(|c| c as usize)
+}
+
+impl<T> Map3D<T> {
+    pub fn is_inside(&self, point: Point3D) -> bool {
+        let [x, y, z] = point.0;
+        x >= 0 && y >= 0 && z >= 0
+            && (x as usize) < self.width && (y as usize) < self.height && (z as usize) < self.depth
+    }
+
+    pub fn set_tile(&mut self, point: Point3D, tile: T) -> bool {
+        if let Some(index) = self.get_index(point) {
+            self.map[index] = tile;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn get_tile(&self, point: Point3D) -> Option<&T> {
+        self.get_index(point).and_then(|i| self.map.get(i))
+    }
+
+    pub fn get_tile_mut(&mut self, point: Point3D) -> Option<&mut T> {
+        self.get_index(point).and_then(|i| self.map.get_mut(i))
+    }
+
+    pub fn get_index(&self, point: Point3D) -> Option<usize> {
+        let [x, y, z] = point.0.map(|c| c as usize);
+        self.is_inside(point).then(|| x + self.width * (y + self.height * z))
+    }
+
+    pub fn iter_points(&self) -> impl Iterator<Item = Point3D> + '_ {
+        (0..(self.depth as isize))
+            .flat_map(|z| (0..(self.height as isize)).map(move |y| (y, z)))
+            .flat_map(|(y, z)| (0..(self.width as isize)).map(move |x| Point3D::new(x, y, z)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.map.iter()
+    }
+
+    pub fn iter_with_points(&self) -> impl Iterator<Item = (Point3D, &T)> + '_ {
+        self.iter_points().zip(self.map.iter())
+    }
+
+    /// The points next to `point` in the [`DIRECTIONS_3D`] that are inside the map.
+    pub fn neighbours(&self, point: Point3D) -> impl Iterator<Item = Point3D> + '_ {
+        DIRECTIONS_3D.iter()
+            .map(move |direction| point + direction.as_point())
+            .filter(|&neighbour| self.is_inside(neighbour))
+    }
+
+    /// Every point reachable from `start` through the [`neighbours`](Self::neighbours) whose
+    /// tile is `passable`, `start` included if it's passable itself.
+    pub fn flood_fill(&self, start: Point3D, passable: impl Fn(&T) -> bool) -> HashSet<Point3D> {
+        self.flood_fill_from([start], passable)
+    }
+
+    /// Like [`flood_fill`](Self::flood_fill), from several points at once.
+    pub fn flood_fill_from(&self, starts: impl IntoIterator<Item = Point3D>, passable: impl Fn(&T) -> bool) -> HashSet<Point3D> {
+        let is_passable = |point| self.get_tile(point).is_some_and(&passable);
+        let mut filled: HashSet<Point3D> = starts.into_iter().filter(|&point| is_passable(point)).collect();
+        let mut queue: VecDeque<Point3D> = filled.iter().copied().collect();
+        while let Some(point) = queue.pop_front() {
+            for neighbour in self.neighbours(point) {
+                if is_passable(neighbour) && filled.insert(neighbour) {
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+        filled
+    }
+
+    /// Faces of the `solid` tiles that don't touch another solid one, counting the ones on the
+    /// edges of the map.
+    pub fn surface_area(&self, solid: impl Fn(&T) -> bool) -> usize {
+        self.count_faces(&solid, |point| !self.get_tile(point).is_some_and(&solid))
+    }
+
+    /// Like [`surface_area`](Self::surface_area) but without the faces of the pockets that
+    /// are enclosed by solid tiles, as the outside can't reach them.
+    pub fn exterior_surface_area(&self, solid: impl Fn(&T) -> bool) -> usize {
+        let [width, height, depth] = [self.width, self.height, self.depth].map(|c| c as isize);
+        let border = self.iter_points().filter(|p| {
+            let [x, y, z] = p.0;
+            x == 0 || y == 0 || z == 0 || x == width - 1 || y == height - 1 || z == depth - 1
+        });
+        let outside = self.flood_fill_from(border, |tile| !solid(tile));
+        self.count_faces(&solid, |point| !self.is_inside(point) || outside.contains(&point))
+    }
+
+    fn count_faces(&self, solid: impl Fn(&T) -> bool, exposed: impl Fn(Point3D) -> bool) -> usize {
+        self.iter_with_points()
+            .filter(|(_, tile)| solid(tile))
+            .map(|(point, _)| DIRECTIONS_3D.iter().filter(|d| exposed(point + d.as_point())).count())
+            .sum()
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn size(&self) -> Point3D {
+        Point3D::new(self.width as isize, self.height as isize, self.depth as isize)
+    }
+}
+
+impl<T: PartialEq> Map3D<T> {
+    pub fn find(&self, tile: &T) -> Option<Point3D> {
+        self.iter_with_points()
+            .find(|&(_, t)| t == tile)
+            .map(|(point, _)| point)
+    }
+}
+
+/// The tiles row by row and layer by layer, like [`Map3D::iter`].
+impl<T> IntoIterator for Map3D<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.into_iter()
+    }
+}
+
+impl Map3D<bool> {
+    /// A map just big enough for `points`, with their tiles set.
+    pub fn from_points(points: impl IntoIterator<Item = Point3D>) -> Result<Self, ParseMap3DError> {
+        let points: Vec<_> = points.into_iter().collect();
+        if let Some(&point) = points.iter().find(|p| p.0.iter().any(|&c| c < 0)) {
+            return Err(ParseMap3DError::NegativePoint(point));
+        }
+        let size = points.iter()
+            .fold(Point3D::ZERO, |size, &point| size.max(point + Point3D::new(1, 1, 1)));
+        let mut map = Self::new_filled(size, false);
+        for point in points {
+            map.set_tile(point, true);
+        }
+        Ok(map)
+    }
+}
+
+/// One `x,y,z` point per line, which get set to `true`.
+impl FromStr for Map3D<bool> {
+    type Err = ParseMap3DError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let points = s.lines()
+            .enumerate()
+            .map(|(line, point)| point.parse().map_err(|source| ParseMap3DError::Point { line: line + 1, source }))
+            .collect::<Result<Vec<Point3D>, _>>()?;
+        Self::from_points(points)
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ParseMap3DError {
+    #[error("Invalid point on line {line}: {source}")]
+    Point { line: usize, source: ParsePoint3DError },
+    #[error("Points can't be negative: {0}")]
+    NegativePoint(Point3D),
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn droplet() -> Map3D<bool> {
+        "2,2,2\n1,2,2\n3,2,2\n2,1,2\n2,3,2\n2,2,1\n2,2,3\n2,2,4\n2,2,6\n1,2,5\n3,2,5\n2,1,5\n2,3,5"
+            .parse()
+            .unwrap()
+    }
+
+    #[rstest]
+    fn parses_point_lists(droplet: Map3D<bool>) {
+        assert_eq!(droplet.size(), Point3D::new(4, 4, 7));
+        assert_eq!(droplet.get_tile(Point3D::new(2, 2, 6)), Some(&true));
+        assert_eq!(droplet.get_tile(Point3D::new(0, 0, 0)), Some(&false));
+        assert_eq!(droplet.get_tile(Point3D::new(4, 0, 0)), None);
+        assert_eq!(droplet.iter().filter(|&&tile| tile).count(), 13);
+    }
+
+    #[rstest]
+    #[case("1,2,3\n4,5", ParseMap3DError::Point { line: 2, source: ParsePoint3DError::InvalidLength { expected: 3, found: 2 } })]
+    #[case("1,2,3\n-1,0,0", ParseMap3DError::NegativePoint(Point3D::new(-1, 0, 0)))]
+    fn rejects_invalid_points(#[case] s: &str, #[case] expected: ParseMap3DError) {
+        assert_eq!(s.parse::<Map3D<bool>>(), Err(expected));
+    }
+
+    #[rstest]
+    fn measures_surfaces(droplet: Map3D<bool>) {
+        assert_eq!(droplet.surface_area(|&tile| tile), 64);
+        assert_eq!(droplet.exterior_surface_area(|&tile| tile), 58);
+    }
+
+    #[test]
+    fn fills_the_connected_tiles() {
+        let mut map = Map3D::new_filled(Point3D::new(3, 3, 3), '.');
+        for point in map.iter_points().collect::<Vec<_>>() {
+            if point.z() == 1 {
+                map.set_tile(point, '#');
+            }
+        }
+
+        let filled = map.flood_fill(Point3D::new(1, 1, 0), |&tile| tile == '.');
+
+        assert_eq!(filled.len(), 9);
+        assert!(filled.iter().all(|point| point.z() == 0));
+        assert!(map.flood_fill(Point3D::new(1, 1, 1), |&tile| tile == '.').is_empty());
+        assert_eq!(map.neighbours(Point3D::new(0, 0, 0)).count(), 3);
+        assert_eq!(map.neighbours(Point3D::new(1, 1, 1)).count(), 6);
+    }
+
+    #[test]
+    #[should_panic(expected = "the size of a map can't be negative")]
+    fn rejects_negative_sizes() {
+        Map3D::new_filled(Point3D::new(2, -1, 2), false);
+    }
+
+    #[test]
+    #[should_panic(expected = "the size of a map can't be negative")]
+    fn rejects_negative_sizes_with_default_tiles() {
+        Map3D::<u8>::new_with_default_tiles(Point3D::new(0, 0, -3));
+    }
+
+    #[test]
+    fn slices_layers() {
+        let mut map = Map3D::new_filled(Point3D::new(3, 2, 2), b'.');
+        map.set_tile(Point3D::new(2, 0, 1), b'#');
+
+        let layers: Vec<_> = map.layers_iter().map(|layer| layer.to_string()).collect();
+
+        assert_eq!(layers, ["...\n...\n", "..#\n...\n"]);
+        assert_eq!(map.layer(1).map(|layer| layer.to_string()).as_deref(), Some("..#\n...\n"));
+        assert_eq!(map.layer(2), None);
+        assert_eq!(map.find(&b'#'), Some(Point3D::new(2, 0, 1)));
+        assert_eq!(map.iter_with_points().nth(5), Some((Point3D::new(2, 1, 0), &b'.')));
+    }
+}
@@ -0,0 +1,547 @@
+//! Combinators for the usual shapes of the inputs: sections separated by blank lines,
+//! lines, comma lists, signed integers anywhere in a line, `key: value` lines and grids.
+//!
+//! Every piece is an [`Input`], which remembers where it starts in the whole input, so
+//! errors point at the line and column of the original text no matter how deep the piece
+//! was split:
+//!
+//! ```
+//! use xmas::parse::{Input, ParseError, Span};
+//!
+//! let input = Input::new("Register A: 729\nRegister B: 0\n\nProgram: 0,1,x");
+//! let [registers, program] = input.sections_n().unwrap();
+//!
+//! let registers: Vec<u64> = registers.ints().unwrap();
+//! let (_, program) = program.key_value(":").unwrap();
+//! let err = program.comma_list::<u8>().unwrap_err();
+//!
+//! assert_eq!(registers, [729, 0]);
+//! assert_eq!(err.span(), Span { line: 4, column: 14 });
+//! ```
+//!
+//! Records whose lines follow a fixed format can derive [`AocParse`] instead, which parses
+//! each field with [`FromInput`]:
+//!
+//! ```
+//! use xmas::{parse::AocParse, point2d::Point2D};
+//!
+//! #[derive(Debug, PartialEq, AocParse)]
+//! #[aoc(fmt = "p={start} v={velocity}")]
+//! struct Robot {
+//!     start: Point2D,
+//!     velocity: Point2D,
+//! }
+//!
+//! let robot: Robot = "p=0,4 v=3,-3".parse().unwrap();
+//!
+//! assert_eq!(robot, Robot { start: Point2D::new(0, 4), velocity: Point2D::new(3, -3) });
+//! ```
+
+use std::{fmt::Display, ops::Range, str::FromStr};
+use thiserror::Error;
+
+use crate::{map2d::Map2D, vector::Vector};
+
+pub use xmas_derive::AocParse;
+
+/// Position in the whole input, both 1 based.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Span {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Display for Span {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseError {
+    #[error("Invalid {expected} `{found}` @ {span}: {reason}")]
+    Invalid { expected: String, found: String, span: Span, reason: String },
+    #[error("Expected {expected} but found `{found}` @ {span}")]
+    Unexpected { expected: String, found: String, span: Span },
+    #[error("Missing {expected} @ {span}")]
+    Missing { expected: String, span: Span },
+    #[error("Expected {expected} sections but found {found} @ {span}")]
+    SectionCount { expected: usize, found: usize, span: Span },
+    #[error("Row of width {found} in a grid of width {expected} @ {span}")]
+    RowWidth { expected: usize, found: usize, span: Span },
+}
+
+impl ParseError {
+    pub fn span(&self) -> Span {
+        match self {
+            ParseError::Invalid { span, .. }
+            | ParseError::Unexpected { span, .. }
+            | ParseError::Missing { span, .. }
+            | ParseError::SectionCount { span, .. }
+            | ParseError::RowWidth { span, .. } => *span,
+        }
+    }
+}
+
+/// A piece of the input and where it starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Input<'a> {
+    text: &'a str,
+    start: Span,
+}
+
+impl<'a> Input<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self { text, start: Span { line: 1, column: 1 } }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Where the piece starts.
+    pub fn span(&self) -> Span {
+        self.start
+    }
+
+    /// Where the byte at `offset` of the piece is.
+    pub fn span_at(&self, offset: usize) -> Span {
+        let before = &self.text[..offset];
+        match before.rfind('\n') {
+            Some(last_newline) => Span {
+                line: self.start.line + before.matches('\n').count(),
+                column: before[last_newline + 1..].chars().count() + 1,
+            },
+            None => Span { line: self.start.line, column: self.start.column + before.chars().count() },
+        }
+    }
+
+    /// Where the piece ends, used to report anything missing from it.
+    pub fn end(&self) -> Span {
+        self.span_at(self.text.len())
+    }
+
+    pub fn slice(&self, range: Range<usize>) -> Self {
+        Self { start: self.span_at(range.start), text: &self.text[range] }
+    }
+
+    /// Removes the whitespace around the piece.
+    pub fn trim(&self) -> Self {
+        let start = self.text.len() - self.text.trim_start().len();
+        let end = self.text.trim_end().len().max(start);
+        self.slice(start..end)
+    }
+
+    /// Lines of the piece, like [`str::lines`].
+    pub fn lines(&self) -> impl Iterator<Item = Input<'a>> + 'a {
+        let is_empty = self.is_empty();
+        let text = self.slice(0..self.text.strip_suffix('\n').unwrap_or(self.text).len());
+        text.split("\n")
+            .filter(move |_| !is_empty)
+            .map(|line| match line.text.strip_suffix('\r') {
+                Some(text) => Input { text, ..line },
+                None => line,
+            })
+    }
+
+    /// Pieces between every `separator`.
+    pub fn split(&self, separator: &'a str) -> impl Iterator<Item = Input<'a>> + 'a {
+        let input = *self;
+        let mut start = 0;
+        let ends = self.text.match_indices(separator)
+            .map(|(i, _)| (i, i + separator.len()))
+            .chain(std::iter::once((self.text.len(), self.text.len())));
+        ends.map(move |(end, next_start)| {
+            let piece = input.slice(start..end);
+            start = next_start;
+            piece
+        })
+    }
+
+    /// The pieces before and after the first `separator`.
+    pub fn split_once(&self, separator: &str) -> Result<(Self, Self), ParseError> {
+        let i = self.text.find(separator)
+            .ok_or_else(|| ParseError::Missing { expected: quote(separator), span: self.end() })?;
+        Ok((self.slice(0..i), self.slice(i + separator.len()..self.text.len())))
+    }
+
+    /// Sections separated by blank lines, ignoring the trailing new lines.
+    pub fn sections(&self) -> Vec<Input<'a>> {
+        let end = self.text.trim_end_matches(['\n', '\r']).len();
+        let text = self.slice(0..end);
+        if text.is_empty() {
+            return vec![];
+        }
+
+        let mut sections = vec![];
+        let mut start = 0;
+        let mut offset = 0;
+        for line in text.text.split_inclusive('\n') {
+            if line.trim().is_empty() {
+                if offset > start {
+                    sections.push(text.slice(start..offset - 1).trim_newlines());
+                }
+                start = offset + line.len();
+            }
+            offset += line.len();
+        }
+        if text.text.len() > start {
+            sections.push(text.slice(start..text.text.len()));
+        }
+        sections
+    }
+
+    /// Exactly `N` sections separated by blank lines.
+    pub fn sections_n<const N: usize>(&self) -> Result<[Input<'a>; N], ParseError> {
+        let sections = self.sections();
+        let found = sections.len();
+        sections.try_into()
+            .map_err(|_| ParseError::SectionCount { expected: N, found, span: self.span() })
+    }
+
+    fn trim_newlines(&self) -> Self {
+        self.slice(0..self.text.trim_end_matches(['\n', '\r']).len())
+    }
+
+    /// Parses the trimmed piece as a `T`.
+    pub fn parse<T>(&self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let trimmed = self.trim();
+        if trimmed.is_empty() {
+            return Err(ParseError::Missing { expected: type_name::<T>(), span: trimmed.span() });
+        }
+        trimmed.text.parse()
+            .map_err(|err: T::Err| ParseError::Invalid {
+                expected: type_name::<T>(),
+                found: trimmed.text.to_string(),
+                span: trimmed.span(),
+                reason: err.to_string(),
+            })
+    }
+
+    /// Every integer in the piece, with its sign if there is a `-` right before it.
+    pub fn ints<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let bytes = self.text.as_bytes();
+        let mut ints = vec![];
+        let mut i = 0;
+        while i < bytes.len() {
+            if !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+            let start = if i > 0 && bytes[i - 1] == b'-' { i - 1 } else { i };
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            ints.push(self.slice(start..i).parse()?);
+        }
+        Ok(ints)
+    }
+
+    /// The values of a list separated by `,`.
+    pub fn comma_list<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        if self.trim().is_empty() {
+            return Ok(vec![]);
+        }
+        self.split(",").map(|item| item.parse()).collect()
+    }
+
+    /// The trimmed key and value of a `key<separator>value` piece.
+    pub fn key_value(&self, separator: &str) -> Result<(Self, Self), ParseError> {
+        let (key, value) = self.split_once(separator)?;
+        Ok((key.trim(), value.trim()))
+    }
+
+    /// The key and value of every non blank line.
+    pub fn key_values(&self, separator: &str) -> Result<Vec<(Self, Self)>, ParseError> {
+        self.lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.key_value(separator))
+            .collect()
+    }
+
+    /// The pieces in place of the placeholders of a format, `literals` being the text around
+    /// them: `["p=", " v=", ""]` for `p={start} v={velocity}`. Trailing new lines are ignored.
+    pub fn captures<const N: usize>(&self, literals: &[&str]) -> Result<[Input<'a>; N], ParseError> {
+        assert_eq!(literals.len(), N + 1, "there must be text around every placeholder");
+        let mut rest = self.trim_newlines();
+        let prefix = literals[0];
+        if !rest.text.starts_with(prefix) {
+            return Err(rest.unexpected(prefix));
+        }
+        rest = rest.slice(prefix.len()..rest.text.len());
+
+        let mut captures = Vec::with_capacity(N);
+        for (i, literal) in literals.iter().enumerate().skip(1) {
+            let end = match rest.text.find(literal) {
+                Some(_) if literal.is_empty() && i == N => rest.text.len(),
+                Some(end) => end,
+                None => return Err(ParseError::Missing { expected: quote(literal), span: rest.end() }),
+            };
+            captures.push(rest.slice(0..end));
+            rest = rest.slice(end + literal.len()..rest.text.len());
+        }
+        if !rest.is_empty() {
+            return Err(ParseError::Unexpected { expected: "the end".to_string(), found: rest.text.to_string(), span: rest.span() });
+        }
+        Ok(captures.try_into().expect("a capture per placeholder"))
+    }
+
+    /// Error for a piece that doesn't start with `expected`.
+    fn unexpected(&self, expected: &str) -> ParseError {
+        let found = self.text.char_indices()
+            .nth(expected.chars().count())
+            .map_or(self.text, |(end, _)| &self.text[..end]);
+        ParseError::Unexpected { expected: quote(expected), found: found.to_string(), span: self.span() }
+    }
+
+    /// A grid with a tile per character, `tile` returns `None` for invalid characters.
+    pub fn grid<T>(&self, mut tile: impl FnMut(char) -> Option<T>) -> Result<Map2D<T>, ParseError> {
+        let mut rows = vec![];
+        for line in self.trim_newlines().lines() {
+            let row = line.text.char_indices()
+                .map(|(i, ch)| tile(ch).ok_or_else(|| ParseError::Unexpected {
+                    expected: "a tile".to_string(),
+                    found: ch.to_string(),
+                    span: line.span_at(i),
+                }))
+                .collect::<Result<Vec<T>, _>>()?;
+            if let Some(first) = rows.first().map(Vec::len).filter(|&width| width != row.len()) {
+                return Err(ParseError::RowWidth { expected: first, found: row.len(), span: line.span() });
+            }
+            rows.push(row);
+        }
+        if rows.is_empty() {
+            return Err(ParseError::Missing { expected: "a grid".to_string(), span: self.span() });
+        }
+        Ok(Map2D::from_rows(rows).expect("rows have the same width"))
+    }
+}
+
+/// Types that can be parsed from a piece of the input, reporting errors where they are in
+/// the whole input. Implemented by `#[derive(AocParse)]`.
+pub trait FromInput: Sized {
+    fn from_input(input: Input<'_>) -> Result<Self, ParseError>;
+}
+
+macro_rules! from_input_with_from_str {
+    ($($t:ty),*) => {
+        $(impl FromInput for $t {
+            fn from_input(input: Input<'_>) -> Result<Self, ParseError> {
+                input.parse()
+            }
+        })*
+    };
+}
+
+from_input_with_from_str!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, char);
+
+impl FromInput for String {
+    fn from_input(input: Input<'_>) -> Result<Self, ParseError> {
+        Ok(input.trim().text.to_string())
+    }
+}
+
+/// A comma list.
+impl<T: FromInput> FromInput for Vec<T> {
+    fn from_input(input: Input<'_>) -> Result<Self, ParseError> {
+        if input.trim().is_empty() {
+            return Ok(vec![]);
+        }
+        input.split(",").map(T::from_input).collect()
+    }
+}
+
+/// `x,y` or `x,y,z`, [`crate::point2d::Point2D`] and [`crate::point3d::Point3D`] included.
+impl<T: FromInput, const N: usize> FromInput for Vector<T, N> {
+    fn from_input(input: Input<'_>) -> Result<Self, ParseError> {
+        let components = Vec::<T>::from_input(input)?;
+        let found = components.len();
+        components.try_into()
+            .map(Vector)
+            .map_err(|_| ParseError::Invalid {
+                expected: format!("{N}D point"),
+                found: input.trim().text.to_string(),
+                span: input.trim().span(),
+                reason: format!("expected {N} coordinates but found {found}"),
+            })
+    }
+}
+
+/// `text` between backticks, with new lines and such escaped.
+fn quote(text: &str) -> String {
+    format!("`{}`", text.escape_debug())
+}
+
+/// Last segment of the type name, `u64` or `Point2D`.
+fn type_name<T>() -> String {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{point2d::Point2D, point3d::Point3D};
+
+    fn span(line: usize, column: usize) -> Span {
+        Span { line, column }
+    }
+
+    #[test]
+    fn keeps_track_of_positions() {
+        let input = Input::new("ab\ncde\nf");
+
+        assert_eq!(input.span_at(0), span(1, 1));
+        assert_eq!(input.span_at(4), span(2, 2));
+        assert_eq!(input.slice(4..8).span_at(3), span(3, 1));
+        assert_eq!(input.end(), span(3, 2));
+    }
+
+    #[test]
+    fn splits_sections() {
+        let input = Input::new("a\nb\n\n\nc\n\nd\n");
+
+        let sections: Vec<_> = input.sections().iter().map(|s| (s.as_str(), s.span())).collect();
+
+        assert_eq!(sections, [("a\nb", span(1, 1)), ("c", span(5, 1)), ("d", span(7, 1))]);
+        assert_eq!(
+            input.sections_n::<2>().unwrap_err(),
+            ParseError::SectionCount { expected: 2, found: 3, span: span(1, 1) },
+        );
+    }
+
+    #[test]
+    fn splits_lines() {
+        let lines: Vec<_> = Input::new("a\r\nb\n\nc\n").lines().map(|l| (l.as_str(), l.span())).collect();
+
+        assert_eq!(lines, [("a", span(1, 1)), ("b", span(2, 1)), ("", span(3, 1)), ("c", span(4, 1))]);
+    }
+
+    #[rstest]
+    #[case("p=0,4 v=3,-3", vec![0, 4, 3, -3])]
+    #[case("Button A: X+94, Y+34", vec![94, 34])]
+    #[case("x-1..-20", vec![-1, -20])]
+    #[case("", vec![])]
+    fn finds_signed_ints(#[case] s: &str, #[case] expected: Vec<i64>) {
+        assert_eq!(Input::new(s).ints::<i64>().unwrap(), expected);
+    }
+
+    #[test]
+    fn reports_where_values_are_invalid() {
+        let input = Input::new("1,2\n3, x,4");
+
+        let err = input.lines().nth(1).unwrap().comma_list::<u8>().unwrap_err();
+
+        assert_eq!(err, ParseError::Invalid {
+            expected: "u8".to_string(),
+            found: "x".to_string(),
+            span: span(2, 4),
+            reason: "invalid digit found in string".to_string(),
+        });
+        assert_eq!(Input::new("-1").ints::<u8>().unwrap_err().span(), span(1, 1));
+    }
+
+    #[test]
+    fn parses_key_values() {
+        let input = Input::new("Register A: 729\nRegister B:\nRegister C 3");
+
+        let pairs = Input::new("Register A: 729\nRegister B: 1\n").key_values(":").unwrap();
+        let pairs: Vec<_> = pairs.iter().map(|(k, v)| (k.as_str(), v.parse::<u64>().unwrap())).collect();
+
+        assert_eq!(pairs, [("Register A", 729), ("Register B", 1)]);
+        assert_eq!(
+            input.lines().nth(1).unwrap().key_value(":").unwrap().1.parse::<u64>().unwrap_err(),
+            ParseError::Missing { expected: "u64".to_string(), span: span(2, 12) },
+        );
+        assert_eq!(
+            input.key_values(":").unwrap_err(),
+            ParseError::Missing { expected: "`:`".to_string(), span: span(3, 13) },
+        );
+    }
+
+    #[test]
+    fn parses_grids_inside_sections() {
+        let input = Input::new("steps\n\n#.\n.#\n\n#.\n.\n");
+        let tile = |ch| matches!(ch, '#' | '.').then_some(ch == '#');
+
+        let [_, grid, uneven] = input.sections_n().unwrap();
+
+        let grid = grid.grid(tile).unwrap();
+        assert_eq!(grid.get_tile(Point2D::new(1, 1)), Some(&true));
+        assert_eq!(grid.size(), Point2D::new(2, 2));
+        assert_eq!(uneven.grid(tile).unwrap_err(), ParseError::RowWidth { expected: 2, found: 1, span: span(7, 1) });
+        assert_eq!(
+            Input::new("#.\n.x").grid(tile).unwrap_err(),
+            ParseError::Unexpected { expected: "a tile".to_string(), found: "x".to_string(), span: span(2, 2) },
+        );
+    }
+
+    #[derive(Debug, PartialEq, AocParse)]
+    #[aoc(fmt = "Register {name}: {value}")]
+    struct Register {
+        name: char,
+        value: u64,
+    }
+
+    #[derive(Debug, PartialEq, AocParse)]
+    #[aoc(fmt = "{first}\n{second} -> {{{target}}}")]
+    struct Wire {
+        first: Register,
+        second: Register,
+        target: Point3D,
+    }
+
+    #[test]
+    fn derives_parsers_from_formats() {
+        let wire: Wire = "Register A: 729\nRegister B: 0 -> {1,-2,3}\n".parse().unwrap();
+
+        assert_eq!(wire, Wire {
+            first: Register { name: 'A', value: 729 },
+            second: Register { name: 'B', value: 0 },
+            target: Point3D::new(1, -2, 3),
+        });
+    }
+
+    #[rstest]
+    #[case("Register A 729", ParseError::Missing { expected: "`: `".to_string(), span: span(1, 15) })]
+    #[case("Reg A: 729", ParseError::Unexpected { expected: "`Register `".to_string(), found: "Reg A: 72".to_string(), span: span(1, 1) })]
+    #[case("Register A: x", ParseError::Invalid {
+        expected: "u64".to_string(),
+        found: "x".to_string(),
+        span: span(1, 13),
+        reason: "invalid digit found in string".to_string(),
+    })]
+    fn reports_where_formats_dont_match(#[case] s: &str, #[case] expected: ParseError) {
+        assert_eq!(s.parse::<Register>().unwrap_err(), expected);
+    }
+
+    #[test]
+    fn reports_errors_of_nested_records_in_the_whole_input() {
+        let err = Wire::from_input(Input::new("\n\nRegister A: 1\nRegister B: 2 -> {1,2}").sections()[0]);
+
+        assert_eq!(err.unwrap_err(), ParseError::Invalid {
+            expected: "3D point".to_string(),
+            found: "1,2".to_string(),
+            span: span(4, 19),
+            reason: "expected 3 coordinates but found 2".to_string(),
+        });
+    }
+}
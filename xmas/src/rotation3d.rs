@@ -0,0 +1,217 @@
+//! The 24 ways to orient a cube, as rotations of [`Point3D`] and [`Direction3D`] that keep
+//! the axes on the axes.
+
+use std::{cmp::Reverse, collections::HashMap, ops};
+
+use crate::{direction::{Axes, QuarterRotation}, direction3d::Direction3D, point3d::Point3D};
+
+/// A proper rotation by quarter turns, stored as the matrix that multiplies column points:
+/// each row has a single `1` or `-1`, and the determinant is `1`.
+///
+/// `a * b` is the rotation by `b` and then by `a`, like the matrices.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Rotation3D([[isize; 3]; 3]);
+
+impl Default for Rotation3D {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Rotation3D {
+    pub const IDENTITY: Self = Self([[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+
+    /// Every orientation, [`IDENTITY`](Self::IDENTITY) first.
+    pub fn all() -> [Self; 24] {
+        const PERMUTATIONS: [[usize; 3]; 6] = [[0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]];
+        let mut rotations = PERMUTATIONS.iter()
+            .flat_map(|&permutation| (0..8).map(move |signs| {
+                Self(std::array::from_fn(|row| {
+                    let mut matrix_row = [0; 3];
+                    matrix_row[permutation[row]] = if signs & (1 << row) == 0 { 1 } else { -1 };
+                    matrix_row
+                }))
+            }))
+            .filter(|rotation| rotation.determinant() == 1);
+        std::array::from_fn(|_| rotations.next().expect("half of the signed permutations are rotations"))
+    }
+
+    /// The same turn as [`Direction3D::turn`] around `axis`.
+    pub fn around(axis: Direction3D, rot: QuarterRotation) -> Self {
+        let [x, y, z] = axis.as_point().0;
+        // point.cross(axis) as a matrix, plus the part of the point along the axis
+        let clockwise = Self([
+            [x * x, x * y + z, x * z - y],
+            [x * y - z, y * y, y * z + x],
+            [x * z + y, y * z - x, z * z],
+        ]);
+        match rot {
+            QuarterRotation::None => Self::IDENTITY,
+            QuarterRotation::Right => clockwise,
+            QuarterRotation::TurnAround => clockwise * clockwise,
+            QuarterRotation::Left => clockwise * clockwise * clockwise,
+        }
+    }
+
+    /// The rotation that undoes this one.
+    pub fn inverse(&self) -> Self {
+        Self(std::array::from_fn(|row| std::array::from_fn(|column| self.0[column][row])))
+    }
+
+    /// This rotation followed by `next`.
+    pub fn then(&self, next: Self) -> Self {
+        next * *self
+    }
+
+    /// Where [`Direction3D::Right`], [`Direction3D::Down`] and [`Direction3D::Front`] end up.
+    pub fn axes(&self) -> [Direction3D; 3] {
+        std::array::from_fn(|column| {
+            let point = Point3D::new(self.0[0][column], self.0[1][column], self.0[2][column]);
+            Direction3D::from_point_in(point, Axes::Screen).expect("the columns are on the axes")
+        })
+    }
+
+    fn determinant(&self) -> isize {
+        let [a, b, c] = self.0.map(Point3D::from);
+        a.dot(b.cross(c))
+    }
+
+    /// The rotation and translation that map the most points of `from` onto points of `to`,
+    /// or `None` if any of them is empty. Ties keep the first rotation of [`Rotation3D::all`]
+    /// and, for that rotation, the smallest translation comparing `x`, then `y` and then `z`.
+    pub fn best_alignment(from: &[Point3D], to: &[Point3D]) -> Option<Alignment> {
+        Self::all().into_iter()
+            .filter_map(|rotation| {
+                let mut translations: HashMap<Point3D, usize> = HashMap::new();
+                for &a in from {
+                    let rotated = rotation * a;
+                    for &b in to {
+                        *translations.entry(b - rotated).or_default() += 1;
+                    }
+                }
+                translations.into_iter()
+                    .max_by_key(|&(translation, matches)| (matches, Reverse(translation.0)))
+                    .map(|(translation, matches)| Alignment { rotation, translation, matches })
+            })
+            .reduce(|best, alignment| if alignment.matches > best.matches { alignment } else { best })
+    }
+}
+
+impl ops::Mul for Rotation3D {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self(std::array::from_fn(|row| std::array::from_fn(|column| {
+            (0..3).map(|i| self.0[row][i] * rhs.0[i][column]).sum()
+        })))
+    }
+}
+
+impl ops::Mul<Point3D> for Rotation3D {
+    type Output = Point3D;
+
+    fn mul(self, rhs: Point3D) -> Self::Output {
+        Point3D::from(self.0.map(|row| Point3D::from(row).dot(rhs)))
+    }
+}
+
+impl ops::Mul<Direction3D> for Rotation3D {
+    type Output = Direction3D;
+
+    fn mul(self, rhs: Direction3D) -> Self::Output {
+        Direction3D::from_point_in(self * rhs.as_point(), Axes::Screen)
+            .expect("rotating a direction gives a direction")
+    }
+}
+
+/// How to move the points of one cloud onto another, see [`Rotation3D::best_alignment`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Alignment {
+    pub rotation: Rotation3D,
+    pub translation: Point3D,
+    /// Points that end up on a point of the other cloud.
+    pub matches: usize,
+}
+
+impl Alignment {
+    pub fn apply(&self, point: Point3D) -> Point3D {
+        self.rotation * point + self.translation
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::{direction::QUARTER_ROTATIONS, direction3d::DIRECTIONS_3D};
+
+    #[test]
+    fn enumerates_the_orientations() {
+        let all = Rotation3D::all();
+
+        assert_eq!(all[0], Rotation3D::IDENTITY);
+        assert_eq!(all.iter().collect::<HashSet<_>>().len(), 24);
+        assert_eq!(all.iter().map(|rotation| rotation.axes()[..2].to_vec()).collect::<HashSet<_>>().len(), 24);
+        for a in all {
+            assert_eq!(a * a.inverse(), Rotation3D::IDENTITY);
+            for b in all {
+                assert!(all.contains(&(a * b)), "{a:?} * {b:?} is a rotation");
+            }
+        }
+    }
+
+    #[test]
+    fn turns_like_directions() {
+        for axis in DIRECTIONS_3D {
+            for rot in QUARTER_ROTATIONS {
+                let rotation = Rotation3D::around(axis, rot);
+                assert_eq!(Rotation3D::around(axis, rot.inverse()), rotation.inverse());
+                for direction in DIRECTIONS_3D {
+                    assert_eq!(rotation * direction, direction.turn(axis, rot));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn composes_in_order() {
+        let first = Rotation3D::around(Direction3D::Front, QuarterRotation::Right);
+        let second = Rotation3D::around(Direction3D::Up, QuarterRotation::Right);
+        let point = Point3D::new(1, 2, 3);
+
+        assert_eq!(first.then(second) * point, second * (first * point));
+        assert_ne!(first.then(second), second.then(first));
+        assert_eq!(first.then(second).inverse(), second.inverse().then(first.inverse()));
+    }
+
+    #[test]
+    fn aligns_point_clouds() {
+        let from: Vec<_> = (0..12)
+            .map(|i| Point3D::new(i * 7 % 11, i * i % 13 - 6, 3 - i * 5 % 17))
+            .chain([Point3D::new(100, 100, 100)])
+            .collect();
+        let expected = Alignment {
+            rotation: Rotation3D::around(Direction3D::Left, QuarterRotation::Right)
+                * Rotation3D::around(Direction3D::Front, QuarterRotation::TurnAround),
+            translation: Point3D::new(68, -1246, -43),
+            matches: 12,
+        };
+        let to: Vec<_> = from[..12].iter()
+            .map(|&point| expected.apply(point))
+            .chain([Point3D::new(-5, 0, 5), Point3D::new(0, 0, 0)])
+            .collect();
+
+        assert_eq!(Rotation3D::best_alignment(&from, &to), Some(expected));
+        assert_eq!(Rotation3D::best_alignment(&from, &[]), None);
+    }
+
+    #[test]
+    fn breaks_alignment_ties_by_the_smallest_translation() {
+        let to = [Point3D::new(5, 0, 0), Point3D::new(1, 2, 3), Point3D::new(1, 2, -4)];
+
+        let alignment = Rotation3D::best_alignment(&[Point3D::ZERO], &to).unwrap();
+
+        assert_eq!(alignment, Alignment { rotation: Rotation3D::IDENTITY, translation: Point3D::new(1, 2, -4), matches: 1 });
+    }
+}
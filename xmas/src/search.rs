@@ -0,0 +1,394 @@
+//! Graph searches (BFS, Dijkstra and A*) over any state that can be hashed.
+//!
+//! A [`Search`] is given the successors of each state with the cost of every move, so the
+//! state can be a point, a point plus the facing [`Direction`](crate::direction::Direction)
+//! or anything else. [`Search::grid`] and [`Search::passable`] build the common one: moving
+//! in the four [`DIRECTIONS`] onto the tiles of a [`Map2D`] a cost function accepts.
+//!
+//! [`Search::all_shortest_paths`] keeps every optimal path instead of a single one,
+//! as a DAG of predecessors.
+
+use std::{cmp::Reverse, collections::{BinaryHeap, HashMap, HashSet, VecDeque}, hash::Hash};
+
+use crate::{direction::DIRECTIONS, keyed_ord::KeyedOrd, map2d::Map2D, point2d::Point2D};
+
+type Successors<'a, S> = Box<dyn Fn(&S) -> Vec<(S, u64)> + 'a>;
+
+pub struct Search<'a, S> {
+    successors: Successors<'a, S>,
+}
+
+/// A path found by a search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S> {
+    /// Every state from the start to the goal, both included
+    pub states: Vec<S>,
+    pub cost: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchResult<S> {
+    /// Cheapest path to the goal, `None` when it can't be reached
+    pub path: Option<Path<S>>,
+    /// Every state expanded before the search ended
+    pub visited: HashSet<S>,
+}
+
+/// Every path with the lowest cost from a start to the goals, stored as the DAG of the
+/// predecessors of each state.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<S> {
+    pub start: S,
+    pub cost: u64,
+    /// Goal states reached with the lowest cost
+    pub goals: Vec<S>,
+    /// States that reach each state with its lowest cost, a state may not lead to any goal
+    pub predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash> ShortestPaths<S> {
+    /// States that are part of any of the shortest paths.
+    pub fn states(&self) -> HashSet<S> {
+        let mut states = HashSet::new();
+        let mut pending = self.goals.clone();
+        while let Some(state) = pending.pop() {
+            if !states.insert(state.clone()) {
+                continue;
+            }
+            if let Some(previous) = self.predecessors.get(&state) {
+                pending.extend(previous.iter().cloned());
+            }
+        }
+        states
+    }
+
+    /// Amount of distinct shortest paths.
+    pub fn count(&self) -> u64 {
+        let mut counts = HashMap::from([(&self.start, 1)]);
+        for state in self.topological_order() {
+            let count = self.predecessors.get(state).into_iter().flatten()
+                .map(|previous| counts.get(previous).copied().unwrap_or(0))
+                .sum();
+            counts.entry(state).or_insert(count);
+        }
+        self.goals.iter().map(|goal| counts[goal]).sum()
+    }
+
+    /// The states that lead to a goal, each one after all of its predecessors. Built with a
+    /// depth first search from the goals that doesn't recurse, as paths can be very long.
+    fn topological_order(&self) -> Vec<&S> {
+        let mut order = vec![];
+        let mut seen = HashSet::new();
+        // A state is pushed again as finished, to be added once its predecessors are
+        let mut pending: Vec<(&S, bool)> = self.goals.iter().map(|goal| (goal, false)).collect();
+        while let Some((state, finished)) = pending.pop() {
+            if finished {
+                order.push(state);
+                continue;
+            }
+            if !seen.insert(state) {
+                continue;
+            }
+            pending.push((state, true));
+            pending.extend(self.predecessors.get(state).into_iter().flatten()
+                .filter(|previous| !seen.contains(previous))
+                .map(|previous| (previous, false)));
+        }
+        order
+    }
+
+    /// Iterates every shortest path, each one from the start to its goal.
+    pub fn paths(&self) -> impl Iterator<Item = Vec<S>> + '_ {
+        // Paths are built backwards, from the goals to the start
+        let mut pending: Vec<Vec<S>> = self.goals.iter()
+            .map(|goal| vec![goal.clone()])
+            .collect();
+        std::iter::from_fn(move || {
+            while let Some(path) = pending.pop() {
+                let last = path.last().unwrap();
+                if last == &self.start {
+                    let mut path = path;
+                    path.reverse();
+                    return Some(path);
+                }
+                for previous in self.predecessors.get(last).into_iter().flatten() {
+                    let mut longer = path.clone();
+                    longer.push(previous.clone());
+                    pending.push(longer);
+                }
+            }
+            None
+        })
+    }
+}
+
+impl<'a> Search<'a, Point2D> {
+    /// Moves to the adjacent tiles of `map` that `cost` returns a cost for, `None` means
+    /// impassable.
+    pub fn grid<T>(map: &'a Map2D<T>, cost: impl Fn(Point2D, &T) -> Option<u64> + 'a) -> Self {
+        Self::new(move |&from| {
+            DIRECTIONS.iter()
+                .map(|dir| from + dir.as_point())
+                .filter_map(|p| map.get_tile(p).and_then(|t| cost(p, t)).map(|c| (p, c)))
+                .collect()
+        })
+    }
+
+    /// Moves to the adjacent tiles that are `passable`, every step costs 1.
+    pub fn passable<T>(map: &'a Map2D<T>, passable: impl Fn(&T) -> bool + 'a) -> Self {
+        Self::grid(map, move |_, t| passable(t).then_some(1))
+    }
+}
+
+impl<'a, S: Clone + Eq + Hash> Search<'a, S> {
+    /// Uses `successors` to get the states reachable from a state, with the cost of each move.
+    pub fn new(successors: impl Fn(&S) -> Vec<(S, u64)> + 'a) -> Self {
+        Self { successors: Box::new(successors) }
+    }
+
+    pub fn successors(&self, state: &S) -> Vec<(S, u64)> {
+        (self.successors)(state)
+    }
+
+    /// Breadth first search, move costs are ignored so the path cost is its amount of steps.
+    pub fn bfs(&self, start: S, is_goal: impl Fn(&S) -> bool) -> SearchResult<S> {
+        let mut parents: HashMap<S, S> = HashMap::new();
+        let mut visited = HashSet::from([start.clone()]);
+        let mut open_list = VecDeque::from([start]);
+
+        while let Some(state) = open_list.pop_front() {
+            if is_goal(&state) {
+                let states = backtrack(&parents, state);
+                let cost = states.len() as u64 - 1;
+                return SearchResult { path: Some(Path { states, cost }), visited };
+            }
+
+            for (next, _) in self.successors(&state) {
+                if visited.insert(next.clone()) {
+                    parents.insert(next.clone(), state.clone());
+                    open_list.push_back(next);
+                }
+            }
+        }
+        SearchResult { path: None, visited }
+    }
+
+    pub fn dijkstra(&self, start: S, is_goal: impl Fn(&S) -> bool) -> SearchResult<S> {
+        self.astar(start, is_goal, |_| 0)
+    }
+
+    /// A* search, `heuristic` must never overestimate the remaining cost to the goal.
+    pub fn astar(&self, start: S, is_goal: impl Fn(&S) -> bool, heuristic: impl Fn(&S) -> u64) -> SearchResult<S> {
+        let mut parents: HashMap<S, S> = HashMap::new();
+        let mut best_costs = HashMap::from([(start.clone(), 0)]);
+        let mut visited = HashSet::new();
+        let mut open_list = BinaryHeap::new();
+        let start_estimate = heuristic(&start);
+        open_list.push(Reverse(KeyedOrd::new((start, 0), start_estimate)));
+
+        while let Some(Reverse(KeyedOrd { value: (state, cost), .. })) = open_list.pop() {
+            if !visited.insert(state.clone()) {
+                continue;
+            }
+
+            if is_goal(&state) {
+                let states = backtrack(&parents, state);
+                return SearchResult { path: Some(Path { states, cost }), visited };
+            }
+
+            for (next, move_cost) in self.successors(&state) {
+                let next_cost = cost + move_cost;
+                if best_costs.get(&next).is_some_and(|&best| best <= next_cost) {
+                    continue;
+                }
+                best_costs.insert(next.clone(), next_cost);
+                parents.insert(next.clone(), state.clone());
+                let estimate = next_cost + heuristic(&next);
+                open_list.push(Reverse(KeyedOrd::new((next, next_cost), estimate)));
+            }
+        }
+        SearchResult { path: None, visited }
+    }
+
+    /// Dijkstra search that keeps every path with the lowest cost to any goal, `None` when
+    /// no goal can be reached. Move costs must be greater than 0.
+    pub fn all_shortest_paths(&self, start: S, is_goal: impl Fn(&S) -> bool) -> Option<ShortestPaths<S>> {
+        let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+        let mut best_costs = HashMap::from([(start.clone(), 0)]);
+        let mut visited = HashSet::new();
+        let mut goals = vec![];
+        let mut goal_cost = None;
+        let mut open_list = BinaryHeap::new();
+        open_list.push(Reverse(KeyedOrd::new((start.clone(), 0), 0)));
+
+        while let Some(Reverse(KeyedOrd { value: (state, cost), .. })) = open_list.pop() {
+            if goal_cost.is_some_and(|goal_cost| cost > goal_cost) {
+                break;
+            }
+            if !visited.insert(state.clone()) {
+                continue;
+            }
+
+            if is_goal(&state) {
+                goal_cost = Some(cost);
+                goals.push(state);
+                continue;
+            }
+
+            for (next, move_cost) in self.successors(&state) {
+                let next_cost = cost + move_cost;
+                match best_costs.get(&next) {
+                    Some(&best) if best < next_cost => continue,
+                    Some(&best) if best == next_cost => {
+                        predecessors.entry(next).or_default().push(state.clone());
+                    }
+                    _ => {
+                        best_costs.insert(next.clone(), next_cost);
+                        predecessors.insert(next.clone(), vec![state.clone()]);
+                        open_list.push(Reverse(KeyedOrd::new((next, next_cost), next_cost)));
+                    }
+                }
+            }
+        }
+
+        goal_cost.map(|cost| ShortestPaths { start, cost, goals, predecessors })
+    }
+}
+
+fn backtrack<S: Clone + Eq + Hash>(parents: &HashMap<S, S>, goal: S) -> Vec<S> {
+    let mut states = vec![goal];
+    while let Some(parent) = parents.get(states.last().unwrap()) {
+        states.push(parent.clone());
+    }
+    states.reverse();
+    states
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{direction::{Direction, QuarterRotation}, map2d::CharMap};
+    use rstest::rstest;
+
+    const MAZE: &str = concat!(
+        "#######\n",
+        "#S...##\n",
+        "###.#.#\n",
+        "#...#E#\n",
+        "#.###.#\n",
+        "#.....#\n",
+        "#######\n",
+    );
+
+    #[rstest]
+    #[case::bfs(0)]
+    #[case::dijkstra(1)]
+    #[case::astar(2)]
+    fn finds_shortest_path(#[case] algorithm: u8) {
+        let map = CharMap::from_str(MAZE).unwrap();
+        let start = map.find(&'S').unwrap();
+        let end = map.find(&'E').unwrap();
+        let search = Search::passable(&map, |t| t != &'#');
+
+        let is_goal = |p: &Point2D| p == &end;
+        let result = match algorithm {
+            0 => search.bfs(start, is_goal),
+            1 => search.dijkstra(start, is_goal),
+            _ => search.astar(start, is_goal, |p| p.manhattan_distance(end) as u64),
+        };
+
+        let path = result.path.unwrap();
+        assert_eq!(path.cost, 14);
+        assert_eq!(path.states.first(), Some(&start));
+        assert_eq!(path.states.last(), Some(&end));
+        assert!(path.states.windows(2).all(|w| w[0].manhattan_distance(w[1]) == 1));
+    }
+
+    #[test]
+    fn reports_unreachable_goal_with_visited_tiles() {
+        let map = CharMap::from_str("S.#E\n").unwrap();
+        let search = Search::passable(&map, |t| t != &'#');
+
+        let result = search.dijkstra(Point2D::new(0, 0), |&p| p == Point2D::new(3, 0));
+
+        assert_eq!(result.path, None);
+        assert_eq!(result.visited, HashSet::from([Point2D::new(0, 0), Point2D::new(1, 0)]));
+    }
+
+    #[test]
+    fn keeps_every_shortest_path() {
+        let map = CharMap::from_str("S..\n.#.\n..E\n").unwrap();
+        let search = Search::passable(&map, |t| t != &'#');
+
+        let paths = search.all_shortest_paths(Point2D::new(0, 0), |&p| p == Point2D::new(2, 2)).unwrap();
+
+        assert_eq!(paths.cost, 4);
+        assert_eq!(paths.count(), 2);
+        assert_eq!(paths.states().len(), 8);
+        let mut all_paths: Vec<_> = paths.paths().collect();
+        all_paths.sort_by_key(|path| path[1].0);
+        assert_eq!(all_paths, [
+            vec![Point2D::new(0, 0), Point2D::new(0, 1), Point2D::new(0, 2), Point2D::new(1, 2), Point2D::new(2, 2)],
+            vec![Point2D::new(0, 0), Point2D::new(1, 0), Point2D::new(2, 0), Point2D::new(2, 1), Point2D::new(2, 2)],
+        ]);
+    }
+
+    #[test]
+    fn counts_paths_to_every_goal_with_the_lowest_cost() {
+        let map = CharMap::from_str("E.S.E\n.....\n").unwrap();
+        let search = Search::passable(&map, |_| true);
+
+        let paths = search.all_shortest_paths(Point2D::new(2, 0), |&p| map.get_tile(p) == Some(&'E')).unwrap();
+
+        assert_eq!(paths.goals.len(), 2);
+        assert_eq!(paths.count(), 2);
+        assert_eq!(paths.states(), HashSet::from([Point2D::new(0, 0), Point2D::new(1, 0), Point2D::new(2, 0), Point2D::new(3, 0), Point2D::new(4, 0)]));
+    }
+
+    #[test]
+    fn uses_tile_costs() {
+        let map = Map2D::<u8>::from_str("191\n111\n").unwrap();
+        let search = Search::grid(&map, |_, &t| Some((t - b'0') as u64));
+
+        let result = search.dijkstra(Point2D::new(0, 0), |&p| p == Point2D::new(2, 0));
+
+        assert_eq!(result.path.unwrap().cost, 4);
+    }
+
+    #[test]
+    fn searches_with_extended_state() {
+        let map = CharMap::from_str("S..\n.#.\n..E\n").unwrap();
+        // Turning costs 1000 like in a reindeer maze, so going right first is cheaper
+        let search = Search::new(|&(point, dir): &(Point2D, Direction)| {
+            let mut successors = vec![
+                ((point, dir.turn(QuarterRotation::Right)), 1000),
+                ((point, dir.turn(QuarterRotation::Left)), 1000),
+            ];
+            let forward = point + dir.as_point();
+            if map.get_tile(forward).is_some_and(|t| t != &'#') {
+                successors.push(((forward, dir), 1));
+            }
+            successors
+        });
+
+        let result = search.dijkstra((Point2D::new(0, 0), Direction::Right), |&(point, _)| point == Point2D::new(2, 2));
+
+        let path = result.path.unwrap();
+        assert_eq!(path.cost, 1004);
+        assert_eq!(path.states[1].0, Point2D::new(1, 0));
+    }
+
+    #[test]
+    fn counts_long_paths_without_recursing() {
+        // Going up one or two at a time, only the steps of two reach the odd numbers
+        let search = Search::new(|&n: &u32| if n.is_multiple_of(2) { vec![(n + 2, 2), (n + 1, 1)] } else { vec![] });
+
+        let paths = search.all_shortest_paths(0, |&n| n == 200_000).unwrap();
+
+        assert_eq!(paths.cost, 200_000);
+        assert_eq!(paths.count(), 1);
+        assert_eq!(paths.states().len(), 100_001);
+    }
+}
@@ -0,0 +1,110 @@
+use std::{fmt::Display, hint::black_box, time::{Duration, Instant}};
+use anyhow::Context;
+use serde::de::DeserializeOwned;
+
+/// Common interface every day crate exposes so it can be run by the `aoc` runner.
+///
+/// The input is parsed once into `Parsed` and then both parts are solved from it.
+/// `Params` holds the values the puzzle text fixes for the real input (grid sizes,
+/// step counts...), its `Default` must match the real puzzle, examples override it
+/// through the params table of their fixture (see [`crate::fixture`]). It should deny unknown
+/// fields, so a mistyped key in a fixture fails instead of running with the defaults.
+pub trait Solution {
+    type Parsed;
+    type Params: Default + DeserializeOwned;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed>;
+    fn part_1(&self, parsed: &Self::Parsed, params: &Self::Params) -> anyhow::Result<Self::Answer1>;
+    fn part_2(&self, parsed: &Self::Parsed, params: &Self::Params) -> anyhow::Result<Self::Answer2>;
+}
+
+/// Object safe version of [`Solution`] so days can be stored and run uniformly,
+/// answers are returned already formatted.
+pub trait DynSolution {
+    fn solve(&self, input: &str, part: Part) -> anyhow::Result<String> {
+        self.solve_with_params(input, part, &toml::Table::new())
+    }
+
+    /// Solves with the default params overridden by the ones in `params`.
+    fn solve_with_params(&self, input: &str, part: Part, params: &toml::Table) -> anyhow::Result<String>;
+
+    /// Parses the input once and solves both parts, timing each step.
+    fn run_timed(&self, input: &str) -> anyhow::Result<RunTimes>;
+}
+
+/// How long each step of a single run took.
+#[derive(Debug, Clone, Copy)]
+pub struct RunTimes {
+    pub parse: Duration,
+    pub part_1: Duration,
+    pub part_2: Duration,
+}
+
+impl<S: Solution> DynSolution for S {
+    fn solve_with_params(&self, input: &str, part: Part, params: &toml::Table) -> anyhow::Result<String> {
+        let params: S::Params = if params.is_empty() {
+            Default::default()
+        } else {
+            toml::Value::Table(params.clone()).try_into().context("Invalid params")?
+        };
+        let parsed = self.parse(input)?;
+        Ok(match part {
+            Part::One => self.part_1(&parsed, &params)?.to_string(),
+            Part::Two => self.part_2(&parsed, &params)?.to_string(),
+        })
+    }
+
+    fn run_timed(&self, input: &str) -> anyhow::Result<RunTimes> {
+        let start = Instant::now();
+        let parsed = black_box(self.parse(black_box(input))?);
+        let parse = start.elapsed();
+
+        let params = S::Params::default();
+        let start = Instant::now();
+        black_box(self.part_1(&parsed, &params)?);
+        let part_1 = start.elapsed();
+
+        let start = Instant::now();
+        black_box(self.part_2(&parsed, &params)?);
+        let part_2 = start.elapsed();
+
+        Ok(RunTimes { parse, part_1, part_2 })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(anyhow::anyhow!("Invalid part {value}, expected 1 or 2")),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Part {}", self.number())
+    }
+}
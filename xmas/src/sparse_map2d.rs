@@ -0,0 +1,234 @@
+use std::{collections::{hash_map, HashMap}, fmt::Display};
+
+use crate::{direction::Axes, map2d::Map2D, point2d::Point2D};
+
+/// A grid that only stores the tiles that were set, for when coordinates can be negative or
+/// the size isn't known in advance. Its bounds grow with the tiles.
+///
+/// The [`Axes`] only matter when rendering it: with the math ones the largest `y` is on top.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseMap2D<Tile = u8> {
+    tiles: HashMap<Point2D, Tile>,
+    axes: Axes,
+}
+
+impl<T> Default for SparseMap2D<T> {
+    fn default() -> Self {
+        Self { tiles: HashMap::new(), axes: Axes::default() }
+    }
+}
+
+impl<T> SparseMap2D<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_axes(self, axes: Axes) -> Self {
+        Self { axes, ..self }
+    }
+
+    pub fn axes(&self) -> Axes {
+        self.axes
+    }
+
+    /// Keeps the tiles of `map` for which `keep` is true, at the same points.
+    pub fn from_map(map: Map2D<T>, mut keep: impl FnMut(&T) -> bool) -> Self {
+        let axes = map.axes();
+        let points: Vec<_> = map.iter_points().collect();
+        let tiles: Self = points.into_iter()
+            .zip(map)
+            .filter(|(_, tile)| keep(tile))
+            .collect();
+        tiles.with_axes(axes)
+    }
+
+    /// Sets the tile at `point`, returning the one that was there.
+    pub fn set_tile(&mut self, point: Point2D, tile: T) -> Option<T> {
+        self.tiles.insert(point, tile)
+    }
+
+    pub fn remove_tile(&mut self, point: Point2D) -> Option<T> {
+        self.tiles.remove(&point)
+    }
+
+    pub fn get_tile(&self, point: Point2D) -> Option<&T> {
+        self.tiles.get(&point)
+    }
+
+    pub fn get_tile_mut(&mut self, point: Point2D) -> Option<&mut T> {
+        self.tiles.get_mut(&point)
+    }
+
+    pub fn contains(&self, point: Point2D) -> bool {
+        self.tiles.contains_key(&point)
+    }
+
+    pub fn retain(&mut self, mut keep: impl FnMut(Point2D, &mut T) -> bool) {
+        self.tiles.retain(|&point, tile| keep(point, tile))
+    }
+
+    /// Number of tiles set.
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    /// Smallest and largest corners of the tiles set, both inclusive.
+    pub fn bounds(&self) -> Option<(Point2D, Point2D)> {
+        let mut points = self.tiles.keys();
+        let first = *points.next()?;
+        Some(points.fold((first, first), |(min, max), &point| (min.min(point), max.max(point))))
+    }
+
+    /// Size of the bounds, zero when there are no tiles.
+    pub fn size(&self) -> Point2D {
+        self.bounds()
+            .map_or(Point2D::ZERO, |(min, max)| max - min + Point2D::new(1, 1))
+    }
+
+    /// Points of the tiles set, in no particular order.
+    pub fn iter_points(&self) -> impl Iterator<Item = Point2D> + '_ {
+        self.tiles.keys().copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.tiles.values()
+    }
+
+    pub fn iter_with_points(&self) -> impl Iterator<Item = (Point2D, &T)> + '_ {
+        self.tiles.iter().map(|(&point, tile)| (point, tile))
+    }
+}
+
+impl<T: Clone> SparseMap2D<T> {
+    /// A dense map of the bounds with the same axes, with `empty` where no tile is set, and
+    /// the offset to add to its points to get the ones of this map.
+    pub fn to_map(&self, empty: T) -> (Map2D<T>, Point2D) {
+        let Some((min, _)) = self.bounds() else {
+            return (Map2D::new_filled(Point2D::ZERO, empty).with_axes(self.axes), Point2D::ZERO);
+        };
+        let mut map = Map2D::new_filled(self.size(), empty).with_axes(self.axes);
+        for (point, tile) in self.iter_with_points() {
+            map.set_tile(point - min, tile.clone());
+        }
+        (map, min)
+    }
+}
+
+impl<T: PartialEq> SparseMap2D<T> {
+    pub fn find(&self, tile: &T) -> Option<Point2D> {
+        self.iter_with_points()
+            .find(|&(_, t)| t == tile)
+            .map(|(point, _)| point)
+    }
+}
+
+/// Every tile of the map.
+impl<T> From<Map2D<T>> for SparseMap2D<T> {
+    fn from(map: Map2D<T>) -> Self {
+        Self::from_map(map, |_| true)
+    }
+}
+
+impl<T> FromIterator<(Point2D, T)> for SparseMap2D<T> {
+    fn from_iter<I: IntoIterator<Item = (Point2D, T)>>(iter: I) -> Self {
+        Self { tiles: iter.into_iter().collect(), axes: Axes::default() }
+    }
+}
+
+impl<T> Extend<(Point2D, T)> for SparseMap2D<T> {
+    fn extend<I: IntoIterator<Item = (Point2D, T)>>(&mut self, iter: I) {
+        self.tiles.extend(iter)
+    }
+}
+
+impl<T> IntoIterator for SparseMap2D<T> {
+    type Item = (Point2D, T);
+    type IntoIter = hash_map::IntoIter<Point2D, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tiles.into_iter()
+    }
+}
+
+impl<T: Clone> SparseMap2D<T> {
+    /// Writes the rows of the bounds, top one first, with `empty` where no tile is set.
+    fn render(&self, f: &mut std::fmt::Formatter<'_>, empty: T, row: impl Fn(&[T]) -> String) -> std::fmt::Result {
+        let (map, _) = self.to_map(empty);
+        for (_, line) in map.rows_top_down() {
+            writeln!(f, "{}", row(line))?;
+        }
+        Ok(())
+    }
+}
+
+/// The bounds, with `.` where no tile is set.
+impl Display for SparseMap2D<u8> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.render(f, b'.', |line| String::from_utf8_lossy(line).into_owned())
+    }
+}
+
+/// The bounds, with `.` where no tile is set.
+impl Display for SparseMap2D<char> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.render(f, '.', |line| line.iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::map2d::CharMap;
+
+    #[test]
+    fn grows_with_negative_coordinates() {
+        let mut map = SparseMap2D::new();
+        assert_eq!(map.bounds(), None);
+
+        map.set_tile(Point2D::new(2, 1), '#');
+        map.set_tile(Point2D::new(-1, -2), '@');
+
+        assert_eq!(map.bounds(), Some((Point2D::new(-1, -2), Point2D::new(2, 1))));
+        assert_eq!(map.size(), Point2D::new(4, 4));
+        assert_eq!(map.get_tile(Point2D::new(-1, -2)), Some(&'@'));
+        assert_eq!(map.get_tile(Point2D::new(0, 0)), None);
+        assert_eq!(map.to_string(), "@...\n....\n....\n...#\n");
+    }
+
+    #[test]
+    fn converts_from_and_to_dense_maps() {
+        let dense = CharMap::from_str("#..\n.#.\n").unwrap();
+
+        let sparse = SparseMap2D::from_map(dense.clone(), |&tile| tile == '#');
+        let (back, offset) = sparse.to_map('.');
+
+        assert_eq!(sparse.len(), 2);
+        assert_eq!(sparse.get_tile(Point2D::new(1, 1)), Some(&'#'));
+        assert_eq!(back.to_string(), "#.\n.#\n");
+        assert_eq!(offset, Point2D::new(0, 0));
+        assert_eq!(SparseMap2D::from(dense).len(), 6);
+    }
+
+    #[test]
+    fn renders_the_math_axes_upside_down() {
+        let mut map = SparseMap2D::new().with_axes(Axes::Math);
+        map.set_tile(Point2D::new(0, 0), '#');
+        map.set_tile(Point2D::new(1, 1), '@');
+
+        assert_eq!(map.to_string(), ".@\n#.\n");
+    }
+
+    #[test]
+    fn empty_maps_render_nothing() {
+        let map: SparseMap2D<u8> = SparseMap2D::new();
+
+        assert_eq!(map.size(), Point2D::new(0, 0));
+        assert_eq!(map.to_string(), "");
+    }
+}
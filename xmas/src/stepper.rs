@@ -0,0 +1,269 @@
+//! Interactive terminal player for step by step simulations.
+//!
+//! Keys:
+//! - `→` / `n`: single step
+//! - `←` / `b`: step back
+//! - `space`: run / pause
+//! - `g`: jump to a step, type its number and press `enter`
+//! - `q` / `esc`: quit
+//!
+//! Every [`Stepper::snapshot_interval`] steps the simulation is cloned, going back replays
+//! the steps from the closest previous snapshot.
+
+use std::{collections::BTreeMap, io::Write, time::Duration};
+use crossterm::{cursor, event::{self, Event, KeyCode, KeyEventKind}, execute, queue, terminal};
+
+/// A simulation that can be advanced one step at a time.
+pub trait Simulation: Clone {
+    /// Advances a step, returns `false` once there is nothing left to simulate.
+    fn step(&mut self) -> bool;
+    fn render(&self) -> String;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Step,
+    Back,
+    TogglePause,
+    JumpTo(usize),
+    Quit,
+}
+
+pub struct Stepper<S> {
+    current: S,
+    step: usize,
+    /// Step at which the simulation ended, once it's known
+    last_step: Option<usize>,
+    snapshots: BTreeMap<usize, S>,
+    snapshot_interval: usize,
+    running: bool,
+    delay: Duration,
+}
+
+impl<S: Simulation> Stepper<S> {
+    pub fn new(simulation: S) -> Self {
+        Self {
+            snapshots: BTreeMap::from([(0, simulation.clone())]),
+            current: simulation,
+            step: 0,
+            last_step: None,
+            snapshot_interval: 100,
+            running: false,
+            delay: Duration::from_millis(100),
+        }
+    }
+
+    /// Steps between snapshots, lower values use more memory but rewind faster.
+    pub fn snapshot_interval(self, snapshot_interval: usize) -> Self {
+        Self { snapshot_interval: snapshot_interval.max(1), ..self }
+    }
+
+    /// Time between steps while running.
+    pub fn delay(self, delay: Duration) -> Self {
+        Self { delay, ..self }
+    }
+
+    pub fn current(&self) -> &S {
+        &self.current
+    }
+
+    pub fn step(&self) -> usize {
+        self.step
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// Applies `command`, returns `false` when the stepper should stop.
+    pub fn handle(&mut self, command: Command) -> bool {
+        match command {
+            Command::Step => {
+                self.running = false;
+                self.forward();
+            }
+            Command::Back => {
+                self.running = false;
+                self.jump_to(self.step.saturating_sub(1));
+            }
+            Command::TogglePause => self.running = !self.running,
+            Command::JumpTo(step) => {
+                self.running = false;
+                self.jump_to(step);
+            }
+            Command::Quit => return false,
+        }
+        true
+    }
+
+    /// Advances a step, returns `false` if the simulation had already ended.
+    pub fn forward(&mut self) -> bool {
+        if self.last_step.is_some_and(|last| self.step >= last) {
+            self.running = false;
+            return false;
+        }
+        if !self.current.step() {
+            self.last_step = Some(self.step);
+            self.running = false;
+            return false;
+        }
+        self.step += 1;
+        if self.step.is_multiple_of(self.snapshot_interval) {
+            self.snapshots.insert(self.step, self.current.clone());
+        }
+        true
+    }
+
+    /// Moves to `step`, or to the last one if the simulation ends before it.
+    pub fn jump_to(&mut self, step: usize) {
+        if step < self.step {
+            let (&snapshot_step, snapshot) = self.snapshots.range(..=step).next_back().unwrap();
+            self.current = snapshot.clone();
+            self.step = snapshot_step;
+        }
+        while self.step < step && self.forward() {}
+    }
+
+    /// Runs the interactive player until the user quits.
+    pub fn run_interactive(&mut self) -> std::io::Result<()> {
+        let mut stdout = std::io::stdout();
+        let _terminal = RawTerminal::enter(&mut stdout)?;
+        self.event_loop(&mut stdout)
+    }
+
+    fn event_loop(&mut self, stdout: &mut impl Write) -> std::io::Result<()> {
+        let mut typed_step: Option<String> = None;
+        loop {
+            self.draw(stdout, typed_step.as_deref())?;
+
+            if self.running && !event::poll(self.delay)? {
+                self.forward();
+                continue;
+            }
+            let key = match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => key.code,
+                _ => continue,
+            };
+
+            if let Some(typed) = &mut typed_step {
+                match key {
+                    KeyCode::Char(ch) if ch.is_ascii_digit() => typed.push(ch),
+                    KeyCode::Backspace => { typed.pop(); }
+                    KeyCode::Enter => {
+                        if let Ok(step) = typed.parse() {
+                            self.handle(Command::JumpTo(step));
+                        }
+                        typed_step = None;
+                    }
+                    KeyCode::Esc => typed_step = None,
+                    _ => (),
+                }
+                continue;
+            }
+
+            let command = match key {
+                KeyCode::Right | KeyCode::Char('n') => Command::Step,
+                KeyCode::Left | KeyCode::Char('b') => Command::Back,
+                KeyCode::Char(' ') => Command::TogglePause,
+                KeyCode::Char('g') => {
+                    typed_step = Some(String::new());
+                    continue;
+                }
+                KeyCode::Char('q') | KeyCode::Esc => Command::Quit,
+                _ => continue,
+            };
+            if !self.handle(command) {
+                return Ok(());
+            }
+        }
+    }
+
+    fn draw(&self, stdout: &mut impl Write, typed_step: Option<&str>) -> std::io::Result<()> {
+        queue!(stdout, terminal::Clear(terminal::ClearType::All), cursor::MoveTo(0, 0))?;
+        // Raw mode doesn't move back to the start of the line on new lines
+        write!(stdout, "{}\r\n", self.current.render().replace('\n', "\r\n"))?;
+
+        let state = match (self.running, self.last_step) {
+            (true, _) => "running",
+            (false, Some(last)) if last == self.step => "finished",
+            (false, _) => "paused",
+        };
+        write!(stdout, "Step {} ({state})\r\n", self.step)?;
+        match typed_step {
+            Some(typed) => write!(stdout, "Jump to step: {typed}")?,
+            None => write!(stdout, "[→/n] step  [←/b] back  [space] run/pause  [g] jump  [q] quit")?,
+        }
+        stdout.flush()
+    }
+}
+
+/// Raw mode on an alternate screen, restoring the terminal when dropped, even on errors
+/// or panics while it's in use.
+struct RawTerminal;
+
+impl RawTerminal {
+    fn enter(stdout: &mut impl Write) -> std::io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        let guard = Self;
+        execute!(stdout, terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(guard)
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        let _ = execute!(std::io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Counter {
+        value: usize,
+        max: usize,
+    }
+
+    impl Simulation for Counter {
+        fn step(&mut self) -> bool {
+            if self.value == self.max {
+                return false;
+            }
+            self.value += 1;
+            true
+        }
+
+        fn render(&self) -> String {
+            self.value.to_string()
+        }
+    }
+
+    #[test]
+    fn rewinds_from_snapshots() {
+        let mut stepper = Stepper::new(Counter { value: 0, max: 100 }).snapshot_interval(4);
+
+        stepper.jump_to(10);
+        stepper.handle(Command::Back);
+        assert_eq!((stepper.step(), stepper.current().value), (9, 9));
+
+        stepper.jump_to(3);
+        assert_eq!((stepper.step(), stepper.current().value), (3, 3));
+    }
+
+    #[test]
+    fn stops_at_the_end_of_the_simulation() {
+        let mut stepper = Stepper::new(Counter { value: 0, max: 5 });
+
+        stepper.handle(Command::JumpTo(20));
+        assert_eq!(stepper.step(), 5);
+
+        stepper.handle(Command::TogglePause);
+        assert!(stepper.is_running());
+        assert!(!stepper.forward());
+        assert!(!stepper.is_running());
+        assert_eq!(stepper.current().render(), "5");
+    }
+}
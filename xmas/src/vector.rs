@@ -0,0 +1,321 @@
+use std::{fmt::Display, iter::Sum, ops, str::FromStr};
+use serde::{de, Deserialize, Deserializer};
+use thiserror::Error;
+
+use crate::num::wrap_val;
+
+/// `N` coordinates of type `T`, [`crate::point2d::Point2D`] and [`crate::point3d::Point3D`]
+/// being the `isize` ones in 2 and 3 dimensions.
+///
+/// Arithmetic works component by component, with a vector or with a scalar on the right.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Vector<T, const N: usize>(pub [T; N]);
+
+impl<T, const N: usize> Vector<T, N> {
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Vector<U, N> {
+        Vector(self.0.map(f))
+    }
+}
+
+impl<T: Copy, const N: usize> Vector<T, N> {
+    /// Combines the components of both vectors one by one.
+    pub fn zip_with<U: Copy, R>(self, other: Vector<U, N>, mut f: impl FnMut(T, U) -> R) -> Vector<R, N> {
+        Vector(std::array::from_fn(|i| f(self.0[i], other.0[i])))
+    }
+
+    pub fn max(&self, other: Self) -> Self where T: Ord {
+        self.zip_with(other, Ord::max)
+    }
+
+    pub fn min(&self, other: Self) -> Self where T: Ord {
+        self.zip_with(other, Ord::min)
+    }
+
+    /// Component-wise product.
+    pub fn scale(&self, other: Self) -> Self where T: ops::Mul<Output = T> {
+        *self * other
+    }
+
+    pub fn dot(&self, other: Self) -> T where T: ops::Mul<Output = T> + Sum {
+        self.0.iter().zip(other.0).map(|(&a, b)| a * b).sum()
+    }
+
+    /// Each component wrapped into `0..range`, also for negative ones.
+    pub fn rem_euclid(&self, range: Self) -> Self
+        where T: ops::Rem<Output = T> + PartialOrd + ops::Add<Output = T> + Default
+    {
+        self.zip_with(range, wrap_val)
+    }
+}
+
+impl<T: Copy> Vector<T, 2> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self([x, y])
+    }
+
+    pub const fn x(&self) -> T {
+        self.0[0]
+    }
+
+    pub const fn y(&self) -> T {
+        self.0[1]
+    }
+}
+
+impl<T: Copy> Vector<T, 3> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self([x, y, z])
+    }
+
+    pub const fn x(&self) -> T {
+        self.0[0]
+    }
+
+    pub const fn y(&self) -> T {
+        self.0[1]
+    }
+
+    pub const fn z(&self) -> T {
+        self.0[2]
+    }
+
+    pub fn cross(&self, other: Self) -> Self where T: ops::Mul<Output = T> + ops::Sub<Output = T> {
+        let [ax, ay, az] = self.0;
+        let [bx, by, bz] = other.0;
+        Self([ay * bz - az * by, az * bx - ax * bz, ax * by - ay * bx])
+    }
+}
+
+macro_rules! zero {
+    ($($t:ty),*) => {
+        $(impl<const N: usize> Vector<$t, N> {
+            pub const ZERO: Self = Self([0; N]);
+        })*
+    };
+}
+
+zero!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl<T: Default + Copy, const N: usize> Default for Vector<T, N> {
+    fn default() -> Self {
+        Self([T::default(); N])
+    }
+}
+
+impl<T: Display, const N: usize> Display for Vector<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(")?;
+        for (i, component) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{component}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl<T, const N: usize> ops::Index<usize> for Vector<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<T, const N: usize> ops::IndexMut<usize> for Vector<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+/// Operators by a vector and by a scalar, with their assigning versions.
+macro_rules! component_wise {
+    ($($op:ident $method:ident $op_assign:ident $method_assign:ident),*) => {
+        $(
+            impl<T: Copy + ops::$op<Output = T>, const N: usize> ops::$op for Vector<T, N> {
+                type Output = Self;
+
+                fn $method(self, rhs: Self) -> Self::Output {
+                    self.zip_with(rhs, ops::$op::$method)
+                }
+            }
+
+            impl<T: Copy + ops::$op<Output = T>, const N: usize> ops::$op<T> for Vector<T, N> {
+                type Output = Self;
+
+                fn $method(self, rhs: T) -> Self::Output {
+                    self.map(|component| ops::$op::$method(component, rhs))
+                }
+            }
+
+            impl<T: Copy + ops::$op<Output = T>, const N: usize> ops::$op_assign for Vector<T, N> {
+                fn $method_assign(&mut self, rhs: Self) {
+                    *self = ops::$op::$method(*self, rhs)
+                }
+            }
+
+            impl<T: Copy + ops::$op<Output = T>, const N: usize> ops::$op_assign<T> for Vector<T, N> {
+                fn $method_assign(&mut self, rhs: T) {
+                    *self = ops::$op::$method(*self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+component_wise!(
+    Add add AddAssign add_assign,
+    Sub sub SubAssign sub_assign,
+    Mul mul MulAssign mul_assign,
+    Div div DivAssign div_assign,
+    Rem rem RemAssign rem_assign
+);
+
+impl<T: ops::Neg<Output = T>, const N: usize> ops::Neg for Vector<T, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.map(ops::Neg::neg)
+    }
+}
+
+impl<T: Copy + Default + ops::Add<Output = T>, const N: usize> Sum for Vector<T, N> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |total, vector| total + vector)
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Vector<T, N> {
+    fn from(value: [T; N]) -> Self {
+        Self(value)
+    }
+}
+
+impl<T, const N: usize> From<Vector<T, N>> for [T; N] {
+    fn from(value: Vector<T, N>) -> Self {
+        value.0
+    }
+}
+
+impl<T> From<(T, T)> for Vector<T, 2> {
+    fn from(value: (T, T)) -> Self {
+        Self([value.0, value.1])
+    }
+}
+
+impl<T> From<(T, T, T)> for Vector<T, 3> {
+    fn from(value: (T, T, T)) -> Self {
+        Self([value.0, value.1, value.2])
+    }
+}
+
+impl<T: Copy, const N: usize> TryFrom<&[T]> for Vector<T, N> {
+    type Error = ParseVectorError;
+
+    fn try_from(value: &[T]) -> Result<Self, Self::Error> {
+        value.try_into()
+            .map(Self)
+            .map_err(|_| ParseVectorError::InvalidLength { expected: N, found: value.len() })
+    }
+}
+
+/// Comma separated components, `1,-2` or `1, -2, 3`.
+impl<T: FromStr, const N: usize> FromStr for Vector<T, N> {
+    type Err = ParseVectorError<T::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let components = s.split(',')
+            .map(|component| component.trim().parse().map_err(ParseVectorError::Component))
+            .collect::<Result<Vec<T>, _>>()?;
+        let found = components.len();
+        components.try_into()
+            .map(Self)
+            .map_err(|_| ParseVectorError::InvalidLength { expected: N, found })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseVectorError<ComponentErr = std::num::ParseIntError> {
+    #[error("should have {expected} components but found {found}")]
+    InvalidLength { expected: usize, found: usize },
+    #[error("couldn't parse a component: {0}")]
+    Component(ComponentErr),
+}
+
+/// A list of `N` components, `[101, 103]` in the params.
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for Vector<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let components = Vec::<T>::deserialize(deserializer)?;
+        let found = components.len();
+        components.try_into()
+            .map(Self)
+            .map_err(|_| de::Error::invalid_length(found, &format!("{N} components").as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    type V2 = Vector<i64, 2>;
+    type V3 = Vector<i32, 3>;
+
+    #[test]
+    fn operates_component_wise() {
+        let a = V2::new(7, -3);
+        let b = V2::new(2, 5);
+
+        assert_eq!(a + b, V2::new(9, 2));
+        assert_eq!(a - b, V2::new(5, -8));
+        assert_eq!(a * b, V2::new(14, -15));
+        assert_eq!(a / b, V2::new(3, 0));
+        assert_eq!(a % b, V2::new(1, -3));
+        assert_eq!(a * 2, V2::new(14, -6));
+        assert_eq!(a / 2, V2::new(3, -1));
+        assert_eq!(-a, V2::new(-7, 3));
+        assert_eq!([a, b, b].into_iter().sum::<V2>(), V2::new(11, 7));
+
+        let mut c = a;
+        c += b;
+        c *= 3;
+        c -= V2::new(1, 1);
+        assert_eq!(c, V2::new(26, 5));
+    }
+
+    #[test]
+    fn multiplies_vectors() {
+        let x = V3::new(1, 0, 0);
+        let y = V3::new(0, 1, 0);
+
+        assert_eq!(x.cross(y), V3::new(0, 0, 1));
+        assert_eq!(y.cross(x), V3::new(0, 0, -1));
+        assert_eq!(V3::new(1, 2, 3).dot(V3::new(4, -5, 6)), 12);
+    }
+
+    #[rstest]
+    #[case(V2::new(6, 5), V2::new(6, 5))]
+    #[case(V2::new(13, -1), V2::new(2, 6))]
+    #[case(V2::new(-11, -7), V2::new(0, 0))]
+    fn wraps_components(#[case] vector: V2, #[case] expected: V2) {
+        assert_eq!(vector.rem_euclid(V2::new(11, 7)), expected);
+    }
+
+    #[rstest]
+    #[case("1,-2", Ok(V2::new(1, -2)))]
+    #[case(" 3 , 4 ", Ok(V2::new(3, 4)))]
+    #[case("1,2,3", Err(ParseVectorError::InvalidLength { expected: 2, found: 3 }))]
+    fn parses_vectors(#[case] s: &str, #[case] expected: Result<V2, ParseVectorError>) {
+        assert_eq!(s.parse(), expected);
+    }
+
+    #[test]
+    fn parses_and_displays_3d_vectors() {
+        let vector: V3 = "1, 2, -3".parse().unwrap();
+
+        assert_eq!(vector.to_string(), "(1, 2, -3)");
+        assert!(matches!("1,x,3".parse::<V3>(), Err(ParseVectorError::Component(_))));
+    }
+}
@@ -0,0 +1,187 @@
+//! Colored terminal rendering of a [`Map2D`].
+//!
+//! Each tile is turned into a [`Glyph`], then overlays (paths, visited tiles, highlighted
+//! points...) are drawn on top of it in the order they were added:
+//!
+//! ```
+//! use xmas::{map2d::CharMap, point2d::Point2D, visualize::Visualizer};
+//!
+//! let map: CharMap = "S.#\n..E\n".parse().unwrap();
+//! Visualizer::new(&map)
+//!     .visited([Point2D::new(0, 1)])
+//!     .path([Point2D::new(0, 0), Point2D::new(1, 0), Point2D::new(1, 1), Point2D::new(2, 1)])
+//!     .highlight([Point2D::new(0, 0), Point2D::new(2, 1)])
+//!     .print();
+//! ```
+
+use std::{collections::HashSet, fmt::Display, io::IsTerminal};
+use crossterm::style::Stylize;
+
+pub use crossterm::style::Color;
+
+use crate::{map2d::Map2D, point2d::Point2D};
+
+/// How a tile is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Glyph {
+    pub ch: char,
+    pub color: Option<Color>,
+}
+
+impl Glyph {
+    pub fn new(ch: char) -> Self {
+        Self { ch, color: None }
+    }
+
+    pub fn colored(ch: char, color: Color) -> Self {
+        Self { ch, color: Some(color) }
+    }
+}
+
+/// Tiles that know how to draw themselves.
+pub trait ToGlyph {
+    fn to_glyph(&self) -> Glyph;
+}
+
+impl ToGlyph for char {
+    fn to_glyph(&self) -> Glyph {
+        Glyph::new(*self)
+    }
+}
+
+impl ToGlyph for u8 {
+    fn to_glyph(&self) -> Glyph {
+        Glyph::new(*self as char)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Overlay {
+    points: HashSet<Point2D>,
+    /// Replaces the tile character when set, otherwise only the color changes
+    ch: Option<char>,
+    color: Color,
+}
+
+type GlyphFn<'a, T> = Box<dyn Fn(Point2D, &T) -> Glyph + 'a>;
+
+pub struct Visualizer<'a, T> {
+    map: &'a Map2D<T>,
+    glyph: GlyphFn<'a, T>,
+    overlays: Vec<Overlay>,
+    colored: bool,
+}
+
+impl<'a, T: ToGlyph> Visualizer<'a, T> {
+    pub fn new(map: &'a Map2D<T>) -> Self {
+        Self::with_glyphs(map, |_, tile| tile.to_glyph())
+    }
+}
+
+impl<'a, T> Visualizer<'a, T> {
+    /// Draws each tile with the glyph returned by `glyph`.
+    pub fn with_glyphs(map: &'a Map2D<T>, glyph: impl Fn(Point2D, &T) -> Glyph + 'a) -> Self {
+        Self {
+            map,
+            glyph: Box::new(glyph),
+            overlays: vec![],
+            colored: std::io::stdout().is_terminal(),
+        }
+    }
+
+    /// Colors are only used when stdout is a terminal by default.
+    pub fn colored(self, colored: bool) -> Self {
+        Self { colored, ..self }
+    }
+
+    /// Draws `ch` (or the tile itself if `None`) with `color` on top of every point.
+    pub fn overlay(mut self, points: impl IntoIterator<Item = Point2D>, ch: Option<char>, color: Color) -> Self {
+        self.overlays.push(Overlay { points: points.into_iter().collect(), ch, color });
+        self
+    }
+
+    pub fn path(self, points: impl IntoIterator<Item = Point2D>) -> Self {
+        self.overlay(points, Some('O'), Color::Green)
+    }
+
+    pub fn visited(self, points: impl IntoIterator<Item = Point2D>) -> Self {
+        self.overlay(points, None, Color::DarkCyan)
+    }
+
+    pub fn highlight(self, points: impl IntoIterator<Item = Point2D>) -> Self {
+        self.overlay(points, None, Color::Red)
+    }
+
+    pub fn render(&self) -> String {
+        self.to_string()
+    }
+
+    pub fn print(&self) {
+        println!("{self}");
+    }
+
+    fn glyph_at(&self, point: Point2D, tile: &T) -> Glyph {
+        let glyph = (self.glyph)(point, tile);
+        self.overlays.iter()
+            .filter(|overlay| overlay.points.contains(&point))
+            .fold(glyph, |glyph, overlay| Glyph {
+                ch: overlay.ch.unwrap_or(glyph.ch),
+                color: Some(overlay.color),
+            })
+    }
+}
+
+impl<T> Display for Visualizer<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.map.rows_top_down() {
+            for (x, tile) in row.iter().enumerate() {
+                let glyph = self.glyph_at(Point2D::new(x as isize, y), tile);
+                match glyph.color {
+                    Some(color) if self.colored => write!(f, "{}", glyph.ch.with(color))?,
+                    _ => write!(f, "{}", glyph.ch)?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::map2d::CharMap;
+
+    #[test]
+    fn renders_like_the_map_without_overlays() {
+        let map = CharMap::from_str("#.#\n..E\n").unwrap();
+
+        let rendered = Visualizer::new(&map).colored(false).render();
+
+        assert_eq!(rendered, map.to_string());
+    }
+
+    #[test]
+    fn later_overlays_are_drawn_on_top() {
+        let map = CharMap::from_str("S..\n..E\n").unwrap();
+
+        let visualizer = Visualizer::new(&map)
+            .path([Point2D::new(0, 0), Point2D::new(1, 0), Point2D::new(2, 0)])
+            .overlay([Point2D::new(1, 0)], Some('x'), Color::Red)
+            .colored(false);
+
+        assert_eq!(visualizer.render(), "OxO\n..E\n");
+    }
+
+    #[test]
+    fn colors_tiles_when_enabled() {
+        let map = CharMap::from_str("a\n").unwrap();
+
+        let visualizer = Visualizer::with_glyphs(&map, |_, &t| Glyph::colored(t, Color::Blue))
+            .colored(true);
+
+        assert_eq!(visualizer.render(), format!("{}\n", 'a'.with(Color::Blue)));
+    }
+}
@@ -0,0 +1,138 @@
+//! `#[derive(AocParse)]`, to parse records described by a format string:
+//!
+//! ```ignore
+//! #[derive(AocParse)]
+//! #[aoc(fmt = "p={start} v={velocity}")]
+//! pub struct Robot {
+//!     start: Point2D,
+//!     velocity: Point2D,
+//! }
+//! ```
+//!
+//! The text between the placeholders must match exactly, and each field is parsed from what
+//! is left in between with `xmas::parse::FromInput`. It implements both `FromInput` and
+//! `FromStr`, with `xmas::parse::ParseError` pointing at where the input didn't match.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident, LitStr};
+
+#[proc_macro_derive(AocParse, attributes(aoc))]
+pub fn derive_aoc_parse(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = named_fields(input)?;
+    let fmt = format_attribute(input)?;
+    let (literals, placeholders) = parse_format(&fmt)?;
+
+    for placeholder in &placeholders {
+        if !fields.iter().any(|field| field == placeholder) {
+            return Err(syn::Error::new(fmt.span(), format!("`{placeholder}` is not a field of `{}`", input.ident)));
+        }
+        if placeholders.iter().filter(|&p| p == placeholder).count() > 1 {
+            return Err(syn::Error::new(fmt.span(), format!("`{placeholder}` appears more than once")));
+        }
+    }
+    if let Some(missing) = fields.iter().find(|&field| !placeholders.iter().any(|placeholder| field == placeholder)) {
+        return Err(syn::Error::new(missing.span(), format!("`{missing}` is missing from the format")));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let captures: Vec<_> = placeholders.iter()
+        .map(|placeholder| Ident::new(placeholder, Span::call_site()))
+        .collect();
+
+    Ok(quote! {
+        impl #impl_generics ::xmas::parse::FromInput for #name #ty_generics #where_clause {
+            fn from_input(input: ::xmas::parse::Input<'_>) -> ::std::result::Result<Self, ::xmas::parse::ParseError> {
+                let [#(#captures),*] = input.captures(&[#(#literals),*])?;
+                ::std::result::Result::Ok(Self {
+                    #(#captures: ::xmas::parse::FromInput::from_input(#captures)?,)*
+                })
+            }
+        }
+
+        impl #impl_generics ::std::str::FromStr for #name #ty_generics #where_clause {
+            type Err = ::xmas::parse::ParseError;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                ::xmas::parse::FromInput::from_input(::xmas::parse::Input::new(s))
+            }
+        }
+    })
+}
+
+fn named_fields(input: &DeriveInput) -> syn::Result<Vec<Ident>> {
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => Ok(fields.named.iter().filter_map(|field| field.ident.clone()).collect()),
+            _ => Err(syn::Error::new_spanned(&input.ident, "AocParse needs a struct with named fields")),
+        },
+        _ => Err(syn::Error::new_spanned(&input.ident, "AocParse can only be derived for structs")),
+    }
+}
+
+/// The `fmt` of `#[aoc(fmt = "...")]`.
+fn format_attribute(input: &DeriveInput) -> syn::Result<LitStr> {
+    let mut fmt = None;
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("aoc")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("fmt") {
+                fmt = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unknown aoc attribute, expected `fmt`"))
+            }
+        })?;
+    }
+    fmt.ok_or_else(|| syn::Error::new_spanned(&input.ident, "missing #[aoc(fmt = \"...\")]"))
+}
+
+/// Splits the format in the text around the placeholders, there's always one more piece of
+/// text than placeholders. `{{` and `}}` are escaped braces.
+fn parse_format(fmt: &LitStr) -> syn::Result<(Vec<String>, Vec<String>)> {
+    let error = |message: &str| syn::Error::new(fmt.span(), message);
+    let mut literals = vec![String::new()];
+    let mut placeholders = vec![];
+    let mut chars = fmt.value().chars().collect::<Vec<_>>().into_iter().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literals.last_mut().unwrap().push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literals.last_mut().unwrap().push('}');
+            }
+            '{' => {
+                let mut placeholder = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(ch) => placeholder.push(ch),
+                        None => return Err(error("unclosed placeholder, use `{{` for a literal `{`")),
+                    }
+                }
+                if placeholder.is_empty() || !placeholder.chars().all(|ch| ch.is_alphanumeric() || ch == '_') {
+                    return Err(error(&format!("invalid placeholder `{{{placeholder}`, expected a field name")));
+                }
+                if !placeholders.is_empty() && literals.last().unwrap().is_empty() {
+                    return Err(error("placeholders must be separated by some text"));
+                }
+                placeholders.push(placeholder);
+                literals.push(String::new());
+            }
+            '}' => return Err(error("unmatched `}`, use `}}` for a literal one")),
+            _ => literals.last_mut().unwrap().push(ch),
+        }
+    }
+    Ok((literals, placeholders))
+}
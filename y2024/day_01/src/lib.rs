@@ -0,0 +1,80 @@
+use std::collections::HashMap;
+use xmas::parse::{Input, ParseError};
+use xmas::solution::Solution;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Parsed = (Vec<u64>, Vec<u64>);
+    type Params = ();
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(parse_nums(input)?)
+    }
+
+    fn part_1(&self, (left_list, right_list): &Self::Parsed, _: &()) -> anyhow::Result<u64> {
+        Ok(nums_diff(left_list, right_list))
+    }
+
+    fn part_2(&self, (left_list, right_list): &Self::Parsed, _: &()) -> anyhow::Result<u64> {
+        Ok(similarity_score(left_list, right_list))
+    }
+}
+
+pub fn nums_diff(left_list: &[u64], right_list: &[u64]) -> u64 {
+    let mut left_list = left_list.to_vec();
+    let mut right_list = right_list.to_vec();
+
+    left_list.sort();
+    right_list.sort();
+
+    left_list.iter().zip(right_list.iter()).map(|(&l, &r)| l.abs_diff(r)).sum()
+}
+
+pub fn similarity_score(left_list: &[u64], right_list: &[u64]) -> u64 {
+    let mut nums_count = HashMap::<u64, u64>::new();
+    for &num in right_list {
+        *nums_count.entry(num).or_default() += 1;
+    }
+
+    left_list.iter()
+        .map(|&i| i * nums_count.get(&i).cloned().unwrap_or(0))
+        .sum()
+}
+
+pub fn parse_nums(s: &str) -> Result<(Vec<u64>, Vec<u64>), ParseError> {
+    let mut left_list = vec![];
+    let mut right_list = vec![];
+
+    for line in Input::new(s).lines() {
+        let mut nums = line.split(" ").filter(|num| !num.trim().is_empty());
+        let mut next_num = || nums.next()
+            .ok_or_else(|| ParseError::Missing { expected: "a number".to_string(), span: line.end() })?
+            .parse::<u64>();
+        let lhs = next_num()?;
+        let rhs = next_num()?;
+
+        left_list.push(lhs);
+        right_list.push(rhs);
+    }
+
+    Ok((left_list, right_list))
+}
+
+#[cfg(test)]
+mod tests {
+    use xmas::parse::Span;
+
+    use super::*;
+
+    #[test]
+    fn reports_where_the_lists_are_wrong() {
+        assert_eq!(
+            parse_nums("3   4\n4\n").unwrap_err(),
+            ParseError::Missing { expected: "a number".to_string(), span: Span { line: 2, column: 2 } },
+        );
+        assert_eq!(parse_nums("3   4\n4   -3").unwrap_err().span(), Span { line: 2, column: 5 });
+    }
+}
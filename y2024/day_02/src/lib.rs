@@ -0,0 +1,134 @@
+use xmas::parse::{Input, ParseError};
+use xmas::solution::Solution;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Parsed = Vec<Report>;
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(parse_reports(input)?)
+    }
+
+    fn part_1(&self, reports: &Self::Parsed, _: &()) -> anyhow::Result<usize> {
+        Ok(count_safe(reports).count)
+    }
+
+    fn part_2(&self, reports: &Self::Parsed, _: &()) -> anyhow::Result<usize> {
+        Ok(count_safe_with_tolerance(reports).count)
+    }
+}
+
+pub type Report = Vec<i64>;
+
+pub struct Count {
+    pub count: usize,
+    pub total: usize,
+}
+
+pub fn count_safe(reports: &[Report]) -> Count {
+    let count = reports.iter()
+        .filter(|&r| report_is_safe(r.iter().cloned()))
+        .count();
+    Count {
+        count,
+        total: reports.len(),
+    }
+}
+
+pub fn count_safe_with_tolerance(reports: &[Report]) -> Count {
+    let count = reports.iter()
+        .filter(|r| report_is_safe_tolerant(r))
+        .count();
+    Count {
+        count,
+        total: reports.len(),
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    None,
+    Increasing,
+    Decreasing,
+}
+
+pub fn parse_reports(input: &str) -> Result<Vec<Report>, ParseError> {
+    Input::new(input).lines()
+        .map(parse_report)
+        .collect()
+}
+
+fn parse_report(line: Input) -> Result<Report, ParseError> {
+    let report = line.split(" ")
+        .filter(|level| !level.trim().is_empty())
+        .map(|level| level.parse())
+        .collect::<Result<Report, _>>()?;
+    if report.is_empty() {
+        return Err(ParseError::Missing { expected: "a level".to_string(), span: line.span() });
+    }
+    Ok(report)
+}
+
+fn report_is_safe(mut report: impl Iterator<Item = i64>) -> bool {
+    let mut kind = Kind::None;
+    let mut previous: i64 = report.next().unwrap();
+    for cur in report {
+        if !(1..=3).contains(&cur.abs_diff(previous)) {
+            return false;
+        }
+
+        match kind {
+            Kind::None => match cur.cmp(&previous) {
+                std::cmp::Ordering::Less => kind = Kind::Decreasing,
+                std::cmp::Ordering::Equal => unreachable!(),
+                std::cmp::Ordering::Greater => kind = Kind::Increasing,
+            },
+            Kind::Increasing => if cur <= previous {
+                return false;
+            },
+            Kind::Decreasing => if cur >= previous {
+                return false;
+            },
+        }
+
+        previous = cur;
+    }
+    true
+}
+
+fn report_is_safe_tolerant(report: &[i64]) -> bool {
+    if report_is_safe(report.iter().cloned()) {
+        return true;
+    }
+
+    for filter_i in 0..report.len() {
+        let modified_report = report.iter()
+            .enumerate()
+            .filter(|(i, _)| *i != filter_i)
+            .map(|(_, num)| *num);
+        if report_is_safe(modified_report) {
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use xmas::parse::Span;
+
+    use super::*;
+
+    #[test]
+    fn reports_empty_and_invalid_reports() {
+        assert_eq!(
+            parse_reports("7 6 4\n\n1 2 3").unwrap_err(),
+            ParseError::Missing { expected: "a level".to_string(), span: Span { line: 2, column: 1 } },
+        );
+        assert_eq!(parse_reports("7 6 x").unwrap_err().span(), Span { line: 1, column: 5 });
+    }
+}
@@ -0,0 +1,118 @@
+use std::str::FromStr;
+
+use regex_static::{Regex, lazy_regex, once_cell::sync::Lazy};
+use xmas::parse::{Input, ParseError};
+use xmas::solution::Solution;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Parsed = Vec<Instruction>;
+    type Params = ();
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(Instruction::many_from_str(input)?)
+    }
+
+    fn part_1(&self, instructions: &Self::Parsed, _: &()) -> anyhow::Result<i64> {
+        Ok(execute_instructions(instructions))
+    }
+
+    fn part_2(&self, instructions: &Self::Parsed, _: &()) -> anyhow::Result<i64> {
+        Ok(execute_instructions_conditionally(instructions))
+    }
+}
+
+// TODO: In the future consider using nom instead?
+static INSTRUCTION_REGEX: Lazy<Regex> = lazy_regex!(r"mul\((\d+),(\d+)\)|do\(\)|don't\(\)");
+
+pub fn execute_instructions(instructions: &[Instruction]) -> i64 {
+    instructions.iter()
+        .map(Instruction::value)
+        .sum()
+}
+
+pub fn execute_instructions_conditionally(instructions: &[Instruction]) -> i64 {
+    let mut enabled = true;
+    instructions.iter()
+        .map(|i| i.execute_conditionally(&mut enabled))
+        .sum()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(i64, i64),
+    Do,
+    Dont,
+}
+
+impl Instruction {
+    pub fn value(&self) -> i64 {
+        match self {
+            Instruction::Mul(a, b) => a * b,
+            _ => 0
+        }
+    }
+
+    pub fn execute_conditionally(&self, enabled: &mut bool) -> i64 {
+        match self {
+            Instruction::Do => {
+                *enabled = true;
+                0
+            },
+            Instruction::Dont => {
+                *enabled = false;
+                0
+            },
+            _ => if *enabled { self.value() } else { 0 }
+        }
+    }
+
+    pub fn many_from_str(s: &str) -> Result<Vec<Self>, ParseError> {
+        parse_instructions(Input::new(s)).collect()
+    }
+}
+
+/// The first instruction of the string.
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = Input::new(s);
+        parse_instructions(input)
+            .next()
+            .unwrap_or_else(|| Err(ParseError::Missing { expected: "an instruction".to_string(), span: input.span() }))
+    }
+}
+
+fn parse_instructions(input: Input<'_>) -> impl Iterator<Item = Result<Instruction, ParseError>> + '_ {
+    INSTRUCTION_REGEX
+        .captures_iter(input.as_str())
+        .map(move |captures| {
+            let num = |i| input.slice(captures.get(i).unwrap().range()).parse::<i64>();
+            Ok(match &captures[0] {
+                ins if ins.starts_with("mul") => Instruction::Mul(num(1)?, num(2)?),
+                ins if ins.starts_with("don't") => Instruction::Dont,
+                ins if ins.starts_with("do") => Instruction::Do,
+                _ => unreachable!(),
+            })
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use xmas::parse::Span;
+
+    use super::*;
+
+    #[test]
+    fn reports_numbers_that_overflow() {
+        let err = Instruction::many_from_str("mul(2,3)do()\nxmul(99999999999999999999,1)").unwrap_err();
+
+        assert!(matches!(err, ParseError::Invalid { span: Span { line: 2, column: 6 }, .. }), "{err}");
+        assert_eq!("don't()".parse(), Ok(Instruction::Dont));
+        assert_eq!("mul(1)".parse::<Instruction>().unwrap_err().span(), Span { line: 1, column: 1 });
+    }
+}
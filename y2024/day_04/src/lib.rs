@@ -1,11 +1,32 @@
 use std::str::FromStr;
 
-use xmas::{direction::{QuarterRotation, DIRECTIONS, DIRECTIONS_8}, map2d::{ByteMap, ParseMapError}, point2d::Point2D};
+use xmas::{direction::{QuarterRotation, DIRECTIONS, DIRECTIONS_8}, map2d::ByteMap, point2d::Point2D};
+use xmas::solution::Solution;
 
-pub fn find_xmas_count(input: &str) -> Result<usize, ParseMapError> {
-    let map = ByteMap::from_str(input)?;
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Parsed = ByteMap;
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(ByteMap::from_str(input)?)
+    }
+
+    fn part_1(&self, map: &Self::Parsed, _: &()) -> anyhow::Result<usize> {
+        Ok(find_xmas_count(map))
+    }
+
+    fn part_2(&self, map: &Self::Parsed, _: &()) -> anyhow::Result<usize> {
+        Ok(find_crossed_mas_count(map))
+    }
+}
+
+pub fn find_xmas_count(map: &ByteMap) -> usize {
     const WORD: &str = "XMAS";
-    Ok(map.iter_points()
+    map.iter_points()
         .flat_map(|p| DIRECTIONS_8.iter().map(move |&dir| (p, dir)))
         .filter(|&(point, dir)| {
             let mut cur = point;
@@ -18,16 +39,15 @@ pub fn find_xmas_count(input: &str) -> Result<usize, ParseMapError> {
             }
             true
         })
-        .count())
+        .count()
 }
 
-pub fn find_crossed_mas_count(input: &str) -> Result<usize, ParseMapError> {
-    let map = ByteMap::from_str(input)?;
+pub fn find_crossed_mas_count(map: &ByteMap) -> usize {
     let tile_is = |point: Point2D, expected: u8| -> bool {
         map.get_tile(point).is_some_and(|&t| t == expected)
     };
 
-    Ok(map.iter_with_points()
+    map.iter_with_points()
         .filter(|&(_, t)| t == &b'A')
         // Find any orientation of "MAS" Arrangements
         .filter(|&(point, _)| DIRECTIONS.iter().any(|&dir| {
@@ -51,5 +71,5 @@ pub fn find_crossed_mas_count(input: &str) -> Result<usize, ParseMapError> {
             }
             true
         }))
-        .count())
+        .count()
 }
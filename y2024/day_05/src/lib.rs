@@ -1,7 +1,30 @@
 use std::{cmp, collections::{HashMap, HashSet}};
+use xmas::parse::{Input, ParseError};
+use xmas::solution::Solution;
 
-type PageOrder = (u64, u64);
-type PrintedPages = Vec<u64>;
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Parsed = (Vec<PageOrder>, Vec<PrintedPages>);
+    type Params = ();
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(parse_info(input)?)
+    }
+
+    fn part_1(&self, (orders, prints): &Self::Parsed, _: &()) -> anyhow::Result<u64> {
+        Ok(process_middle_page_sum(orders, prints))
+    }
+
+    fn part_2(&self, (orders, prints): &Self::Parsed, _: &()) -> anyhow::Result<u64> {
+        Ok(reordered_middle_page_sum(orders, prints))
+    }
+}
+
+pub type PageOrder = (u64, u64);
+pub type PrintedPages = Vec<u64>;
 
 #[derive(Debug, Clone)]
 struct PagePriorities(HashMap<u64, u64>);
@@ -27,7 +50,7 @@ impl PagePriorities {
     }
 
     pub fn get(&self, page: &u64) -> Option<&u64> {
-        self.0.get(&page)
+        self.0.get(page)
     }
 
     fn set_priority_recursively(&mut self, page: u64, priority: u64, orders: &[PageOrder]) {
@@ -39,14 +62,10 @@ impl PagePriorities {
     }
 }
 
-pub fn process_middle_page_sum(input: &str) -> u64 {
-    let (orders, prints) = parse_info(input);
-
+pub fn process_middle_page_sum(orders: &[PageOrder], prints: &[PrintedPages]) -> u64 {
     let mut sum = 0;
-    let mut count = 0;
     // O(n^3) this can be optimized
-    // println!("Ordered:");
-    for print in &prints {
+    for print in prints {
         
         let ordered = print.iter()
             .enumerate()
@@ -61,17 +80,13 @@ pub fn process_middle_page_sum(input: &str) -> u64 {
         }
 
         let middle = print[print.len() / 2];
-        // println!("{:?} = ({})", print, middle);
         sum += middle;
-        count += 1;
     }
-    println!("Ordered count: {}/{}", count, prints.len());
     sum
 }
 
-pub fn reordered_middle_page_sum(input: &str) -> u64 {
-    let (orders, mut prints) = parse_info(input);
-
+pub fn reordered_middle_page_sum(orders: &[PageOrder], prints: &[PrintedPages]) -> u64 {
+    let mut prints = prints.to_vec();
     let mut sum = 0;
     for print in &mut prints {
         let pages_set = print.iter().cloned().collect::<HashSet<_>>();
@@ -89,26 +104,20 @@ pub fn reordered_middle_page_sum(input: &str) -> u64 {
         }
 
         sum += print[print.len() / 2];
-        // println!("{sum}");
     }
     sum
 }
 
-fn parse_info(s: &str) -> (Vec<PageOrder>, Vec<PrintedPages>) {
-    s.split_once("\n\n")
-        .map(|(first, second)| (parse_orders(first), parse_pages(second)))
-        .unwrap()
-}
-
-fn parse_orders(s: &str) -> Vec<PageOrder> {
-    s.lines()
-        .map(|l| l.split_once('|').unwrap())
-        .map(|(left, right)| (left.parse().unwrap(), right.parse().unwrap()))
-        .collect()
-}
-
-fn parse_pages(s: &str) -> Vec<PrintedPages> {
-    s.lines()
-        .map(|l| l.split(',').map(|n| n.parse().unwrap()).collect())
-        .collect()
+fn parse_info(s: &str) -> Result<(Vec<PageOrder>, Vec<PrintedPages>), ParseError> {
+    let [orders, pages] = Input::new(s).sections_n()?;
+    let orders = orders.lines()
+        .map(|l| {
+            let (before, after) = l.split_once("|")?;
+            Ok((before.parse()?, after.parse()?))
+        })
+        .collect::<Result<_, ParseError>>()?;
+    let pages = pages.lines()
+        .map(|l| l.comma_list())
+        .collect::<Result<_, _>>()?;
+    Ok((orders, pages))
 }
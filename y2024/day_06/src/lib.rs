@@ -1,26 +1,42 @@
-use std::{collections::HashSet, str::FromStr, time::Instant};
+use std::{collections::HashSet, str::FromStr};
 
 use anyhow::Context;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use xmas::{direction::{Direction, QuarterRotation}, map2d::ByteMap, point2d::Point2D};
+use xmas::solution::Solution;
 
+pub struct Puzzle;
 
-pub fn get_patrol_visited_count(input: &str) -> Result<usize, anyhow::Error> {
-    let map = ByteMap::from_str(input)?;
-    let start = find_start(&map).context("No starting point")?;
+impl Solution for Puzzle {
+    type Parsed = ByteMap;
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-    let visited = get_visited_tiles(&map, start);
-    println!("Visited {}/{}", visited.len(), map.width() * map.height());
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(ByteMap::from_str(input)?)
+    }
+
+    fn part_1(&self, map: &Self::Parsed, _: &()) -> anyhow::Result<usize> {
+        get_patrol_visited_count(map)
+    }
+
+    fn part_2(&self, map: &Self::Parsed, _: &()) -> anyhow::Result<usize> {
+        find_loop_count(map)
+    }
+}
+
+pub fn get_patrol_visited_count(map: &ByteMap) -> Result<usize, anyhow::Error> {
+    let start = find_start(map).context("No starting point")?;
 
+    let visited = get_visited_tiles(map, start);
     Ok(visited.len())
 }
 
-pub fn find_loop_count(input: &str) -> Result<usize, anyhow::Error> {
-    let map = ByteMap::from_str(input)?;
-    let start = find_start(&map).context("No starting point")?;
+pub fn find_loop_count(map: &ByteMap) -> Result<usize, anyhow::Error> {
+    let start = find_start(map).context("No starting point")?;
 
-    let original_visited = get_visited_tiles(&map, start);
-    let start_time = Instant::now();
+    let original_visited = get_visited_tiles(map, start);
     let count = original_visited.into_par_iter()
         .filter(|&new_obstacle| {
             if new_obstacle == start {
@@ -36,7 +52,6 @@ pub fn find_loop_count(input: &str) -> Result<usize, anyhow::Error> {
                 match map.get_tile(next_pos) {
                     Some(tile) if tile == &b'#' || next_pos == new_obstacle => {
                         if path.contains(&(cur_pos, cur_dir)) {
-                            // println!("Found loop");
                             return true;
                         }
                         path.insert((cur_pos, cur_dir));
@@ -50,8 +65,6 @@ pub fn find_loop_count(input: &str) -> Result<usize, anyhow::Error> {
             false
         })
         .count();
-    let elapsed = start_time.elapsed();
-    println!("Calculated in {:?}", elapsed);
     Ok(count)
 }
 
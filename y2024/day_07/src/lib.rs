@@ -0,0 +1,109 @@
+use std::ops::{Add, Mul};
+use xmas::parse::{Input, ParseError};
+use xmas::solution::Solution;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Parsed = Vec<Equation>;
+    type Params = ();
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(parse_equations(input)?)
+    }
+
+    fn part_1(&self, equations: &Self::Parsed, _: &()) -> anyhow::Result<u64> {
+        Ok(get_calibration_sum_1(equations))
+    }
+
+    fn part_2(&self, equations: &Self::Parsed, _: &()) -> anyhow::Result<u64> {
+        Ok(get_calibration_sum_2(equations))
+    }
+}
+
+type OperatorFns<'a> = [&'a dyn Fn(u64, u64) -> u64];
+
+/// Expected result and the numbers to combine.
+pub type Equation = (u64, Vec<u64>);
+
+pub fn get_calibration_sum_1(equations: &[Equation]) -> u64 {
+    get_calibration_result(equations, &[&Add::add, &Mul::mul])
+}
+
+pub fn get_calibration_sum_2(equations: &[Equation]) -> u64 {
+    get_calibration_result(equations, &[&Add::add, &Mul::mul, &concatenate_nums])
+}
+
+pub fn parse_equations(input: &str) -> Result<Vec<Equation>, ParseError> {
+    Input::new(input).lines()
+        .map(parse_equation)
+        .collect()
+}
+
+/// `<expected>: <num> <num>...`, with at least one number to combine.
+fn parse_equation(line: Input) -> Result<Equation, ParseError> {
+    let (expected, nums_input) = line.split_once(":")?;
+    let expected = expected.parse()?;
+    let nums = nums_input.split(" ")
+        .filter(|num| !num.trim().is_empty())
+        .map(|num| num.parse())
+        .collect::<Result<Vec<_>, _>>()?;
+    if nums.is_empty() {
+        return Err(ParseError::Missing { expected: "a number".to_string(), span: nums_input.end() });
+    }
+    Ok((expected, nums))
+}
+
+fn get_calibration_result(equations: &[Equation], operators: &OperatorFns) -> u64 {
+    equations.iter()
+        .filter(|(expected, nums)| can_be_solved(*expected, nums, operators))
+        .map(|(expected, _)| expected)
+        .sum()
+}
+
+fn can_be_solved(expected: u64, nums: &[u64], operators: &OperatorFns) -> bool {
+    let first = *nums.first().unwrap();
+    can_be_solved_recursive(expected, first, &nums[1..], operators)
+}
+
+fn can_be_solved_recursive(expected: u64, already_calculated: u64, nums: &[u64], operators: &OperatorFns) -> bool {
+    let next = match nums.first().cloned() {
+        Some(n) => n,
+        None => return expected == already_calculated,
+    };
+
+    let rest = &nums[1..];
+    operators.iter()
+        .any(|op| {
+            let calculated = op(already_calculated, next);
+            if calculated > expected {
+                return false;
+            }
+            can_be_solved_recursive(expected, calculated, rest, operators)
+        })
+}
+
+fn concatenate_nums(lhs: u64, rhs: u64) -> u64 {
+    let digits = rhs.ilog10();
+    let mult = 10u64.pow(digits + 1);
+    lhs * mult + rhs
+}
+
+#[cfg(test)]
+mod tests {
+    use xmas::parse::Span;
+
+    use super::*;
+
+    #[test]
+    fn reports_where_equations_are_wrong() {
+        assert_eq!(
+            parse_equations("190: 10 19\n190:\n").unwrap_err(),
+            ParseError::Missing { expected: "a number".to_string(), span: Span { line: 2, column: 5 } },
+        );
+        assert_eq!(parse_equations("190 10 19").unwrap_err().span(), Span { line: 1, column: 10 });
+        assert_eq!(parse_equations("190: 10 x9").unwrap_err().span(), Span { line: 1, column: 9 });
+    }
+}
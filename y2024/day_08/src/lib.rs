@@ -1,10 +1,32 @@
 use std::{collections::{HashMap, HashSet}, str::FromStr};
-use xmas::{map2d::{ByteMap, ParseMapError}, point2d::Point2D};
+use xmas::{map2d::{ByteMap, ParseMapError}, point2d::Point2D, SparseMap2D};
+use xmas::solution::Solution;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Parsed = AntennaMap;
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(AntennaMap::from_str(input)?)
+    }
+
+    fn part_1(&self, map: &Self::Parsed, _: &()) -> anyhow::Result<usize> {
+        Ok(calculate_antinodes_locations(map))
+    }
+
+    fn part_2(&self, map: &Self::Parsed, _: &()) -> anyhow::Result<usize> {
+        Ok(calculate_antinodes_locations_with_resonance(map))
+    }
+}
 
 type Frequency = u8;
 
 #[derive(Debug)]
-struct AntennaMap {
+pub struct AntennaMap {
     map: ByteMap,
     antennas_by_id: HashMap<Frequency, Vec<Point2D>>,
 }
@@ -34,32 +56,26 @@ impl FromStr for AntennaMap {
         let map = ByteMap::from_str(s)?;
         let mut antennas_by_id = HashMap::new();
         for (point, tile) in map.iter_with_points().filter(|&(_, t)| t != &b'.' && t != &b'#') {
-            let entry = antennas_by_id.entry(*tile).or_insert_with(|| vec![]);
+            let entry: &mut Vec<_> = antennas_by_id.entry(*tile).or_default();
             entry.push(point);
         }
         Ok(Self { map, antennas_by_id })
     }
 }
 
-pub fn calculate_antinodes_locations(input: &str) -> Result<usize, ParseMapError> {
-    let map = AntennaMap::from_str(&input)?;
-
-    let mut antinodes = HashSet::new();
+pub fn calculate_antinodes_locations(map: &AntennaMap) -> usize {
+    let mut antinodes = SparseMap2D::new();
     for (_frequency, a, b) in map.find_unique_pairs() {
-        // println!("{}: {} <-> {}", char::from_u32(*frequency as u32).unwrap(), point, other);
         let diff = b - a;
-        antinodes.insert(b + diff);
-        antinodes.insert(a - diff);
+        antinodes.set_tile(b + diff, b'#');
+        antinodes.set_tile(a - diff, b'#');
     }
-    antinodes.retain(|p| map.is_inside(*p));
-    // println!("{:?}", antinodes);
+    antinodes.retain(|p, _| map.is_inside(p));
 
-    Ok(antinodes.len())
+    antinodes.len()
 }
 
-pub fn calculate_antinodes_locations_with_resonance(input: &str) -> Result<usize, ParseMapError> {
-    let map = AntennaMap::from_str(&input)?;
-
+pub fn calculate_antinodes_locations_with_resonance(map: &AntennaMap) -> usize {
     let mut antinodes = HashSet::new();
     for (_frequency, a, b) in map.find_unique_pairs() {
         let diff = b - a;
@@ -75,5 +91,5 @@ pub fn calculate_antinodes_locations_with_resonance(input: &str) -> Result<usize
         }
     }
 
-    Ok(antinodes.len())
+    antinodes.len()
 }
@@ -1,5 +1,26 @@
-use std::char;
+use xmas::parse::{Input, ParseError};
+use xmas::solution::Solution;
 
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Parsed = Vec<Block>;
+    type Params = ();
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(parse_disk_map(input)?)
+    }
+
+    fn part_1(&self, blocks: &Self::Parsed, _: &()) -> anyhow::Result<u64> {
+        Ok(get_disk_checksum(blocks))
+    }
+
+    fn part_2(&self, blocks: &Self::Parsed, _: &()) -> anyhow::Result<u64> {
+        Ok(get_disk_checksum_method_2(blocks))
+    }
+}
 
 #[derive(Debug, Clone, Copy)]
 struct ID(u64);
@@ -21,7 +42,7 @@ impl ID {
 }
 
 #[derive(Debug, Clone)]
-struct Block {
+pub struct Block {
     id: ID,
     length: u8,
 }
@@ -37,8 +58,7 @@ struct BlockLayout {
 }
 
 impl BlockLayout {
-    fn from_num_layout(s: &str) -> Self {
-        let blocks = get_file_layout(s.trim()).collect();
+    fn new(blocks: Vec<Block>) -> Self {
         Self { blocks }
     }
 
@@ -50,10 +70,8 @@ impl BlockLayout {
             .next()
             .unwrap();
 
-        // self.debug_display();
         for id_to_move in (1..=max_id).rev() {
             let (mut block_i, required_length) = self.find_index_and_length(id_to_move).unwrap();
-            // println!("{}: Requires {} !!!! {:?}", id_to_move, required_length, self.blocks[block_i].id.actual_id());
             let space_to_place_search = self.blocks[..block_i]
                 .iter_mut()
                 .enumerate()
@@ -62,7 +80,6 @@ impl BlockLayout {
                 Some(b) => b,
                 None => continue,
             };
-            // println!("Found space at index {} with space {}", free_i, space_to_place.length);
 
             space_to_place.length -= required_length;
             if space_to_place.length == 0 {
@@ -74,7 +91,6 @@ impl BlockLayout {
             self.blocks[block_i].id = ID::EMPTY;
             self.blocks.insert(free_i, Block { id, length });
             self.trim_end();
-            // self.debug_display();
         }
     }
 
@@ -105,34 +121,16 @@ impl BlockLayout {
         }
         sum
     }
-
-    fn debug_display(&self) {
-        let mut s = String::new();
-        for block in &self.blocks {
-            let ch = match block.id.actual_id() {
-                Some(id) => char::from_u32(id as u32 + b'0' as u32).unwrap(),
-                None => '.',
-            };
-            for _ in 0..block.length {
-                s.push(ch);
-            }
-        }
-        println!("{}", s);
-    }
 }
 
-pub fn get_disk_checksum(input: &str) -> u64 {
-    let layout_iter = get_file_layout(input.trim());
-    // println!("{:?}", layout);
-    let uncompressed = get_uncompressed_layout(layout_iter);
-    // println!("Uncompressed has length {}", uncompressed.len());
+pub fn get_disk_checksum(blocks: &[Block]) -> u64 {
+    let uncompressed = get_uncompressed_layout(blocks.iter().cloned());
     calculate_checksum(&uncompressed)
 }
 
-pub fn get_disk_checksum_method_2(input: &str) -> u64 {
-    let mut layout = BlockLayout::from_num_layout(input);
+pub fn get_disk_checksum_method_2(blocks: &[Block]) -> u64 {
+    let mut layout = BlockLayout::new(blocks.to_vec());
     layout.reorganize_method_2();
-    // layout.debug_display();
     layout.checksum()
 }
 
@@ -164,7 +162,6 @@ fn calculate_checksum(input: &[ID]) -> u64 {
         };
         let add_to_sum = i as u64 * sum_id;
         sum += add_to_sum;
-        // println!("{} * {} = {} => {}", i, sum_id, add_to_sum, sum);
     }
     sum
 }
@@ -179,14 +176,19 @@ fn get_uncompressed_layout(input: impl Iterator<Item = Block>) -> Vec<ID> {
     bytes
 }
 
-fn get_file_layout(input: &str) -> impl Iterator<Item = Block> + '_ {
+/// The blocks of a disk map, one digit per block alternating between files and free space.
+pub fn parse_disk_map(input: &str) -> Result<Vec<Block>, ParseError> {
+    let input = Input::new(input).trim();
+    if input.is_empty() {
+        return Err(ParseError::Missing { expected: "a disk map".to_string(), span: input.span() });
+    }
+
     let mut is_file = true;
     let mut cur_id = 0u64;
-    input.bytes()
-        .map(move |b| {
-            // println!("{} =", char::from_u32(b as u32).unwrap());
-            // println!("{}", b - b'0');
-            let length = b - b'0';
+    input.as_str().char_indices()
+        .map(|(i, ch)| {
+            let length = ch.to_digit(10)
+                .ok_or_else(|| ParseError::Unexpected { expected: "a digit".to_string(), found: ch.to_string(), span: input.span_at(i) })?;
             let id = if is_file {
                 cur_id += 1;
                 ID(cur_id)
@@ -194,8 +196,23 @@ fn get_file_layout(input: &str) -> impl Iterator<Item = Block> + '_ {
                 ID::EMPTY
             };
             is_file = !is_file;
-            Block { id, length }
+            Ok(Block { id, length: length as u8 })
         })
+        .collect()
 }
 
+#[cfg(test)]
+mod tests {
+    use xmas::parse::Span;
+
+    use super::*;
 
+    #[test]
+    fn reports_what_is_not_a_digit() {
+        assert_eq!(
+            parse_disk_map("2333-133").unwrap_err(),
+            ParseError::Unexpected { expected: "a digit".to_string(), found: "-".to_string(), span: Span { line: 1, column: 5 } },
+        );
+        assert_eq!(parse_disk_map("").unwrap_err().span(), Span { line: 1, column: 1 });
+    }
+}
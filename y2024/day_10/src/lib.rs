@@ -0,0 +1,66 @@
+use std::str::FromStr;
+
+use xmas::{direction::DIRECTIONS, map2d::ByteMap, point2d::Point2D, search::Search};
+use xmas::solution::Solution;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Parsed = ByteMap;
+    type Params = ();
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(ByteMap::from_str(input)?)
+    }
+
+    fn part_1(&self, map: &Self::Parsed, _: &()) -> anyhow::Result<u64> {
+        Ok(calculate_hiking_score(map))
+    }
+
+    fn part_2(&self, map: &Self::Parsed, _: &()) -> anyhow::Result<u64> {
+        Ok(calculate_hiking_ratings(map))
+    }
+}
+
+pub fn calculate_hiking_score(map: &ByteMap) -> u64 {
+    let search = hiking_search(map);
+    trailheads(map)
+        .map(|start| {
+            // Exploring every reachable tile, so the goal is never found
+            search.bfs(start, |_| false)
+                .visited
+                .iter()
+                .filter(|&&p| map.get_tile(p) == Some(&b'9'))
+                .count() as u64
+        })
+        .sum()
+}
+
+pub fn calculate_hiking_ratings(map: &ByteMap) -> u64 {
+    let search = hiking_search(map);
+    // Every trail has the same length, so all of them are shortest paths to some 9
+    trailheads(map)
+        .filter_map(|start| search.all_shortest_paths(start, |&p| map.get_tile(p) == Some(&b'9')))
+        .map(|paths| paths.count())
+        .sum()
+}
+
+/// Moves uphill one height at a time.
+fn hiking_search(map: &ByteMap) -> Search<'_, Point2D> {
+    Search::new(move |&from| {
+        let next_value = map.get_tile(from).unwrap() + 1;
+        DIRECTIONS.iter()
+            .map(|d| from + d.as_point())
+            .filter(|&p| map.get_tile(p) == Some(&next_value))
+            .map(|p| (p, 1))
+            .collect()
+    })
+}
+
+fn trailheads(map: &ByteMap) -> impl Iterator<Item = Point2D> + '_ {
+    map.iter_with_points()
+        .filter(|&(_, t)| t == &b'0')
+        .map(|(start, _)| start)
+}
@@ -1,4 +1,41 @@
-use std::{collections::HashMap, num::ParseIntError};
+use std::collections::HashMap;
+use serde::Deserialize;
+use xmas::parse::{Input, ParseError};
+use xmas::solution::Solution;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Parsed = Vec<u64>;
+    type Params = Params;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(parse_stones(input)?)
+    }
+
+    fn part_1(&self, stones: &Self::Parsed, params: &Params) -> anyhow::Result<usize> {
+        Ok(calculate_stone_count(stones, params.part_1_blinks))
+    }
+
+    fn part_2(&self, stones: &Self::Parsed, params: &Params) -> anyhow::Result<usize> {
+        Ok(calculate_stone_count(stones, params.part_2_blinks))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub part_1_blinks: u64,
+    pub part_2_blinks: u64,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { part_1_blinks: 25, part_2_blinks: 75 }
+    }
+}
 
 // Cache was added on part 2
 
@@ -16,7 +53,6 @@ impl StoneCache {
         }
 
         if let Some(&count) = self.count_result_after_steps.get(&(num, steps)) {
-            // println!("Using cache!");
             return count;
         }
 
@@ -42,7 +78,7 @@ impl StoneCache {
             }
     
             let stone_s = num.to_string();
-            if stone_s.len() % 2 != 0 {
+            if !stone_s.len().is_multiple_of(2) {
                 return StoneResult::Change(num * 2024);
             }
     
@@ -60,20 +96,33 @@ enum StoneResult {
     Split(u64, u64),
 }
 
-pub fn calculate_stone_count(input: &str, blinks: u64) -> Result<usize, ParseIntError> {
-    let stones = input.trim()
-        .split_whitespace()
-        .map(|s| s.parse::<u64>())
-        .collect::<Result<Vec<_>, _>>()?;
+pub fn parse_stones(input: &str) -> Result<Vec<u64>, ParseError> {
+    Input::new(input).trim()
+        .split(" ")
+        .filter(|stone| !stone.trim().is_empty())
+        .map(|stone| stone.parse())
+        .collect()
+}
 
+pub fn calculate_stone_count(stones: &[u64], blinks: u64) -> usize {
     let mut cache = StoneCache::default();
     let mut count = 0;
-    for stone in &stones {
+    for stone in stones {
         count += cache.get_or_create_count_after(*stone, blinks);
     }
 
-    println!("Cache ended up with {} immediate result entries and {} results after X steps entries!",
-        cache.step_results.len(), cache.count_result_after_steps.len());
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use xmas::parse::Span;
 
-    Ok(count)
+    use super::*;
+
+    #[test]
+    fn reports_invalid_stones() {
+        assert_eq!(parse_stones("125  17\n"), Ok(vec![125, 17]));
+        assert_eq!(parse_stones("125 -17").unwrap_err().span(), Span { line: 1, column: 5 });
+    }
 }
@@ -1,22 +1,40 @@
 use std::{collections::HashSet, str::FromStr};
-use xmas::{direction::{QuarterRotation, DIRECTIONS}, map2d::{ByteMap, ParseMapError}, point2d::Point2D};
+use xmas::{direction::{QuarterRotation, DIRECTIONS}, map2d::ByteMap, point2d::Point2D};
+use xmas::solution::Solution;
 
-pub fn calculate_fence_costs(input: &str) -> Result<u64, ParseMapError> {
-    let map = ByteMap::from_str(input)?;
+pub struct Puzzle;
 
-    let mut checked_tiles = HashSet::new();
-    Ok(map.iter_points()
-        .flat_map(|p| check_cost_from(p, &map, &mut checked_tiles))
-        .sum())
+impl Solution for Puzzle {
+    type Parsed = ByteMap;
+    type Params = ();
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(ByteMap::from_str(input)?)
+    }
+
+    fn part_1(&self, map: &Self::Parsed, _: &()) -> anyhow::Result<u64> {
+        Ok(calculate_fence_costs(map))
+    }
+
+    fn part_2(&self, map: &Self::Parsed, _: &()) -> anyhow::Result<u64> {
+        Ok(calculate_fence_costs_with_discount(map))
+    }
 }
 
-pub fn calculate_fence_costs_with_discount(input: &str) -> Result<u64, ParseMapError> {
-    let map = ByteMap::from_str(input)?;
+pub fn calculate_fence_costs(map: &ByteMap) -> u64 {
+    let mut checked_tiles = HashSet::new();
+    map.iter_points()
+        .flat_map(|p| check_cost_from(p, map, &mut checked_tiles))
+        .sum()
+}
 
+pub fn calculate_fence_costs_with_discount(map: &ByteMap) -> u64 {
     let mut checked_tiles = HashSet::new();
-    Ok(map.iter_points()
-        .flat_map(|p| check_cost_from_with_discount(p, &map, &mut checked_tiles))
-        .sum())
+    map.iter_points()
+        .flat_map(|p| check_cost_from_with_discount(p, map, &mut checked_tiles))
+        .sum()
 }
 
 fn get_area_tiles(point: Point2D, map: &ByteMap, checked_tiles: &mut HashSet<Point2D>) -> Option<HashSet<Point2D>> {
@@ -53,10 +71,7 @@ fn get_area_tiles(point: Point2D, map: &ByteMap, checked_tiles: &mut HashSet<Poi
 }
 
 fn check_cost_from(point: Point2D, map: &ByteMap, checked_tiles: &mut HashSet<Point2D>) -> Option<u64> {
-    let area_tiles = match get_area_tiles(point, map, checked_tiles) {
-        Some(t) => t,
-        None => return None,
-    };
+    let area_tiles = get_area_tiles(point, map, checked_tiles)?;
 
     let perimeter = area_tiles.iter()
         .flat_map(|&p| DIRECTIONS.iter().map(move |d| p + d.as_point()))
@@ -69,10 +84,7 @@ fn check_cost_from(point: Point2D, map: &ByteMap, checked_tiles: &mut HashSet<Po
 
 // I was about to fuse the perimeter edges, but counting corners is much more efficient and easier
 fn check_cost_from_with_discount(point: Point2D, map: &ByteMap, checked_tiles: &mut HashSet<Point2D>) -> Option<u64> {
-    let area_tiles = match get_area_tiles(point, map, checked_tiles) {
-        Some(t) => t,
-        None => return None,
-    };
+    let area_tiles = get_area_tiles(point, map, checked_tiles)?;
 
     let corners = area_tiles.iter()
         .flat_map(|&p| DIRECTIONS.iter().map(move |&d| (p, d)))
@@ -0,0 +1,133 @@
+use std::str::FromStr;
+
+use num::Rational64;
+use xmas::point2d::Point2D;
+use xmas::parse::{AocParse, FromInput, Input, ParseError};
+use xmas::solution::Solution;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Parsed = Vec<Game>;
+    type Params = ();
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(Game::many_from_str(input)?)
+    }
+
+    fn part_1(&self, games: &Self::Parsed, _: &()) -> anyhow::Result<u64> {
+        Ok(lowest_token_cost(games, 0))
+    }
+
+    fn part_2(&self, games: &Self::Parsed, _: &()) -> anyhow::Result<u64> {
+        Ok(lowest_token_cost(games, 10000000000000))
+    }
+}
+
+const A_COST: u64 = 3;
+const B_COST: u64 = 1;
+
+#[derive(Debug, Clone, Copy)]
+struct Line {
+    slope: Rational64,
+    bias: Rational64,
+}
+
+impl Line {
+    fn eval(&self, x: Rational64) -> Rational64 {
+        x * self.slope + self.bias
+    }
+
+    fn intersection_with(&self, other: &Self) -> Option<Rational64> {
+        let new_slope = self.slope - other.slope;
+        if new_slope != Rational64::ZERO {
+            let x = (other.bias - self.bias) / new_slope;
+            Some(x)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Game {
+    button_a: Point2D,
+    button_b: Point2D,
+    prize: Point2D,
+}
+
+/// A game as written in the input.
+#[derive(Debug, Clone, AocParse)]
+#[aoc(fmt = "Button A: X+{ax}, Y+{ay}\nButton B: X+{bx}, Y+{by}\nPrize: X={px}, Y={py}")]
+struct GameRecord {
+    ax: isize,
+    ay: isize,
+    bx: isize,
+    by: isize,
+    px: isize,
+    py: isize,
+}
+
+impl From<GameRecord> for Game {
+    fn from(r: GameRecord) -> Self {
+        Game { button_a: Point2D::new(r.ax, r.ay), button_b: Point2D::new(r.bx, r.by), prize: Point2D::new(r.px, r.py) }
+    }
+}
+
+impl Game {
+    fn many_from_str(s: &str) -> Result<Vec<Self>, ParseError> {
+        Input::new(s).sections()
+            .into_iter()
+            .map(|section| GameRecord::from_input(section).map(Game::from))
+            .collect()
+    }
+
+    fn with_prize_adder(&self, prize_adder: u64) -> Self {
+        let prize_adder = prize_adder as isize;
+        Game { prize: self.prize + Point2D::new(prize_adder, prize_adder), ..self.clone() }
+    }
+
+    fn lowest_token_cost(&self) -> Option<u64> {
+        self.lowest_button_presses()
+            .map(|(a, b)| a * A_COST + b * B_COST)
+    }
+
+    fn lowest_button_presses(&self) -> Option<(u64, u64)> {
+        let x_line = Self::line_for_prize(self.button_a.x() as u64, self.button_b.x() as u64, self.prize.x() as u64);
+        let y_line = Self::line_for_prize(self.button_a.y() as u64, self.button_b.y() as u64, self.prize.y() as u64);
+
+        let a = match x_line.intersection_with(&y_line) {
+            Some(x) if x.is_integer() => x,
+            _ => return None,
+        };
+
+        let b = match x_line.eval(a) {
+            y if y.is_integer() => y.to_integer() as u64,
+            _ => return None,
+        };
+
+        Some((a.to_integer() as u64, b))
+    }
+
+    fn line_for_prize(a: u64, b: u64, p: u64) -> Line {
+        let slope = -Rational64::new(a as i64, b as i64);
+        let bias = Rational64::new(p as i64, b as i64);
+        Line { slope, bias }
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(GameRecord::from_str(s)?.into())
+    }
+}
+
+pub fn lowest_token_cost(games: &[Game], prize_adder: u64) -> u64 {
+    games.iter()
+        .map(|g| g.with_prize_adder(prize_adder).lowest_token_cost().unwrap_or_default())
+        .sum()
+}
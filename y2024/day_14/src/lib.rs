@@ -0,0 +1,185 @@
+use std::collections::HashSet;
+use anyhow::Context;
+use xmas::{map2d::CharMap, point2d::Point2D};
+use serde::Deserialize;
+use xmas::parse::{AocParse, FromInput, Input};
+use xmas::solution::Solution;
+use xmas::stepper::{Simulation, Stepper};
+use xmas::visualize::{Color, Visualizer};
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Parsed = Vec<Robot>;
+    type Params = Params;
+    type Answer1 = usize;
+    type Answer2 = isize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        let robots = Input::new(input).lines()
+            .map(Robot::from_input)
+            .collect::<Result<_, _>>()?;
+        Ok(robots)
+    }
+
+    fn part_1(&self, robots: &Self::Parsed, params: &Params) -> anyhow::Result<usize> {
+        Ok(calculate_safety_factor(robots, params.seconds, params.space))
+    }
+
+    fn part_2(&self, robots: &Self::Parsed, params: &Params) -> anyhow::Result<isize> {
+        let seconds = find_christmas_tree_time(robots, params.space)
+            .context("No time has every robot on a position of its own")?;
+        if params.visualize {
+            print_robots(robots, seconds, params.space);
+        }
+        if params.interactive {
+            simulate_step_by_step(robots, params.space, seconds)?;
+        }
+        Ok(seconds)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub seconds: isize,
+    pub space: Point2D,
+    /// Prints the robots at the time the tree is found
+    pub visualize: bool,
+    /// Opens an interactive player at the time the tree is found
+    pub interactive: bool,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { seconds: 100, space: Point2D::new(101, 103), visualize: false, interactive: false }
+    }
+}
+
+pub fn calculate_safety_factor(robots: &[Robot], seconds: isize, space: Point2D) -> usize {
+    let mut quadrants = [0; 4];
+    let [mx, my] = (space / 2).0;
+    for robot in robots {
+        let position = robot.predict_position(seconds, space);
+        match position.0 {
+            [x, y] if x < mx && y < my => quadrants[0] += 1,
+            [x, y] if x > mx && y < my => quadrants[1] += 1,
+            [x, y] if x < mx && y > my => quadrants[2] += 1,
+            [x, y] if x > mx && y > my => quadrants[3] += 1,
+            _ => (),
+        }
+    }
+
+    quadrants.into_iter()
+        .reduce(|a, b| a * b)
+        .unwrap_or(0)
+}
+
+/// The first time at which no two robots share a position, as they are placed that way to
+/// make up the picture. Positions repeat after `space.x() * space.y()` seconds, so it gives
+/// up after that.
+pub fn find_christmas_tree_time(robots: &[Robot], space: Point2D) -> Option<isize> {
+    (1..=(space.x() * space.y()))
+        .find(|&seconds| robot_positions(robots, seconds, space).len() == robots.len())
+}
+
+/// Prints the robots after `seconds`.
+pub fn print_robots(robots: &[Robot], seconds: isize, space: Point2D) {
+    let map = CharMap::new_filled(space, '.');
+    println!("After {} second/s:", seconds);
+    Visualizer::new(&map)
+        .overlay(robot_positions(robots, seconds, space), Some('#'), Color::Green)
+        .print();
+}
+
+fn robot_positions(robots: &[Robot], seconds: isize, space: Point2D) -> HashSet<Point2D> {
+    robots.iter()
+        .map(|robot| robot.predict_position(seconds, space))
+        .collect()
+}
+
+/// Opens an interactive player to watch the robots move, starting at `seconds`.
+pub fn simulate_step_by_step(robots: &[Robot], space: Point2D, seconds: isize) -> std::io::Result<()> {
+    let simulation = RobotsSimulation { robots: robots.to_vec(), space, seconds: 0 };
+    let mut stepper = Stepper::new(simulation);
+    stepper.jump_to(seconds as usize);
+    stepper.run_interactive()
+}
+
+#[derive(Debug, Clone)]
+struct RobotsSimulation {
+    robots: Vec<Robot>,
+    space: Point2D,
+    seconds: isize,
+}
+
+impl Simulation for RobotsSimulation {
+    fn step(&mut self) -> bool {
+        self.seconds += 1;
+        true
+    }
+
+    fn render(&self) -> String {
+        let positions = self.robots.iter()
+            .map(|robot| robot.predict_position(self.seconds, self.space));
+        let map = CharMap::new_filled(self.space, '.');
+        let rendered = Visualizer::new(&map)
+            .overlay(positions, Some('#'), Color::Green)
+            .render();
+        rendered
+    }
+}
+
+#[derive(Debug, Clone, AocParse)]
+#[aoc(fmt = "p={start} v={velocity}")]
+pub struct Robot {
+    start: Point2D,
+    velocity: Point2D,
+}
+
+impl Robot {
+    fn predict_position(&self, seconds: isize, space: Point2D) -> Point2D {
+        (self.start + self.velocity * seconds).rem_euclid(space)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+    use xmas::parse::{ParseError, Span};
+
+    use super::*;
+
+    #[test]
+    fn wraps_around() {
+        let robot = Robot { start: Point2D::new(4, 1), velocity: Point2D::new(2, -3) };
+
+        let position = robot.predict_position(1, Point2D::new(11, 7));
+
+        assert_eq!(position, Point2D::new(6, 5));
+    }
+
+    #[test]
+    fn finds_the_time_no_robots_overlap() {
+        // Each pair meets once, at 1 and 2 seconds, and they are all apart at 3
+        let robots = Puzzle.parse("p=0,0 v=1,0\np=1,0 v=0,0\np=0,1 v=1,0\np=2,1 v=0,0\n").unwrap();
+        let space = Point2D::new(5, 5);
+
+        assert_eq!(find_christmas_tree_time(&robots, space), Some(3));
+        assert_eq!(find_christmas_tree_time(&robots[..2], space), Some(2));
+        assert_eq!(find_christmas_tree_time(&[robots[0].clone(), robots[0].clone()], space), None);
+    }
+
+    #[test]
+    fn reports_malformed_robots() {
+        assert_eq!(
+            Robot::from_str("p=0,4 v=3").unwrap_err(),
+            ParseError::Invalid {
+                expected: "2D point".to_string(),
+                found: "3".to_string(),
+                span: Span { line: 1, column: 9 },
+                reason: "expected 2 coordinates but found 1".to_string(),
+            },
+        );
+    }
+}
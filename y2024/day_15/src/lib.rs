@@ -0,0 +1,261 @@
+use std::{cell::RefCell, collections::HashSet, rc::Rc, str::FromStr};
+use serde::Deserialize;
+use thiserror::Error;
+use xmas::{direction::Direction, map2d::CharMap, parse::{self, Input}, point2d::Point2D};
+use xmas::stepper::{Simulation, Stepper};
+use xmas::visualize::{Color, Glyph, Visualizer};
+use xmas::solution::Solution;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Parsed = (Warehouse, Vec<Direction>);
+    type Params = Params;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        let [warehouse_s, movements_s] = Input::new(input).sections_n()?;
+        let warehouse = Warehouse::parse(warehouse_s)?;
+        let movements = parse_directions(movements_s)?;
+        Ok((warehouse, movements))
+    }
+
+    fn part_1(&self, (warehouse, movements): &Self::Parsed, params: &Params) -> anyhow::Result<isize> {
+        if params.interactive {
+            watch_robot(warehouse.clone(), movements)?;
+        }
+        Ok(box_gps_sum(warehouse, movements))
+    }
+
+    fn part_2(&self, (warehouse, movements): &Self::Parsed, params: &Params) -> anyhow::Result<isize> {
+        if params.interactive {
+            watch_robot(warehouse.clone_wide_version(), movements)?;
+        }
+        Ok(box_gps_sum_wide(warehouse, movements))
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// Opens an interactive player to watch the robot move before solving
+    pub interactive: bool,
+}
+
+pub fn box_gps_sum(warehouse: &Warehouse, movements: &[Direction]) -> isize {
+    let mut warehouse = warehouse.clone();
+
+    for &dir in movements {
+        warehouse.move_robot(dir);
+    }
+
+    warehouse.box_gps_sum()
+}
+
+pub fn box_gps_sum_wide(warehouse: &Warehouse, movements: &[Direction]) -> isize {
+    let mut warehouse = warehouse.clone_wide_version();
+
+    for &dir in movements {
+        warehouse.move_robot(dir);
+    }
+
+    warehouse.box_gps_sum()
+}
+
+/// Opens an interactive player that moves the robot one movement per step.
+pub fn watch_robot(warehouse: Warehouse, movements: &[Direction]) -> std::io::Result<()> {
+    let simulation = RobotMovements { warehouse, movements: movements.into(), next: 0 };
+    Stepper::new(simulation).run_interactive()
+}
+
+#[derive(Debug, Clone)]
+struct RobotMovements {
+    warehouse: Warehouse,
+    movements: Rc<[Direction]>,
+    next: usize,
+}
+
+impl Simulation for RobotMovements {
+    fn step(&mut self) -> bool {
+        match self.movements.get(self.next) {
+            Some(&dir) => {
+                self.warehouse.move_robot(dir);
+                self.next += 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn render(&self) -> String {
+        let next = match self.movements.get(self.next) {
+            Some(dir) => format!("{:?}", dir),
+            None => "-".to_string(),
+        };
+        format!("{}\nNext move: {next}", self.warehouse.render())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Warehouse {
+    map: CharMap,
+    robot: Point2D,
+    wide: bool,
+}
+
+impl Warehouse {
+    pub fn new(map: CharMap) -> Self {
+        let robot = map.iter_with_points()
+            .find(|&(_, tile)| tile == &'@')
+            .map(|(p, _)| p)
+            .unwrap();
+        Self { map, robot, wide: false }
+    }
+
+    pub fn clone_wide_version(&self) -> Self {
+        let new_size = Point2D::new(self.map.width() as isize * 2, self.map.height() as isize);
+        let mut new_map = CharMap::new_filled(new_size, '.');
+        for (point, tile) in self.map.iter_with_points() {
+            let new_tiles = match tile {
+                '#' => ['#', '#'],
+                '.' => ['.', '.'],
+                '@' => ['@', '.'],
+                'O' => ['[', ']'],
+                _ => unreachable!(),
+            };
+
+            let new_point = Point2D::new(point.x() * 2, point.y());
+            new_map.set_tile(new_point, new_tiles[0]);
+            new_map.set_tile(new_point + Point2D::new(1, 0), new_tiles[1]);
+        }
+        Self { wide: true, ..Self::new(new_map) }
+    }
+
+    pub fn box_gps_sum(&self) -> isize {
+        self.map.iter_with_points()
+            .filter_map(|(p, t)| matches!(t, 'O' | '[').then_some(p))
+            .map(|p| p.x() + (p.y() * 100))
+            .sum()
+    }
+
+    fn move_robot(&mut self, dir: Direction) {
+        let start_pos = self.robot;
+        let new_target_pos = start_pos + dir.as_point();
+        
+        let push_boxes = RefCell::new(vec![]);
+        let mut already_pushing = HashSet::new();
+        // True if movement is valid, false if cancelled
+        let mut try_push = |point: Point2D| -> bool {
+            let pushing_box = match self.map.get_tile(point) {
+                Some('O' | '[') => point,
+                Some(']') => point + Point2D::new(-1, 0),
+                Some('#') => {
+                    return false;
+                }
+                Some(_) => return true,
+                None => unreachable!(),
+            };
+
+            if already_pushing.contains(&pushing_box) {
+                return true;
+            }
+
+            push_boxes.borrow_mut().push(pushing_box);
+            already_pushing.insert(pushing_box);
+            true
+        };
+
+        if !try_push(new_target_pos) {
+            return;
+        }
+
+        let mut i = 0;
+        while i < push_boxes.borrow().len() {
+            let box_pos = push_boxes.borrow()[i];
+            let new_box_pos = box_pos + dir.as_point();
+            if !try_push(new_box_pos) {
+                return;
+            }
+
+            if self.wide {
+                let right_point = new_box_pos + Point2D::new(1, 0);
+                if !try_push(right_point) {
+                    return;
+                }
+            }
+
+            i += 1;
+        }
+
+        for &pushed_box in &already_pushing {
+            self.map.set_tile(pushed_box, '.');
+            if self.wide {
+                self.map.set_tile(pushed_box + Point2D::new(1, 0), '.');
+            }
+        }
+
+        for &pushed_box in &already_pushing {
+            let new_point = pushed_box + dir.as_point();
+            if self.wide {
+                self.map.set_tile(new_point, '[');
+                self.map.set_tile(new_point + Point2D::new(1, 0), ']');
+            } else {
+                self.map.set_tile(new_point, 'O');
+            }
+        }
+
+        self.robot = new_target_pos;
+        self.map.set_tile(start_pos, '.');
+        self.map.set_tile(new_target_pos, '@');
+    }
+
+    fn render(&self) -> String {
+        Visualizer::with_glyphs(&self.map, |_, &tile| match tile {
+            '#' => Glyph::colored(tile, Color::DarkGrey),
+            'O' | '[' | ']' => Glyph::colored(tile, Color::Yellow),
+            _ => Glyph::new(tile),
+        })
+            .highlight([self.robot])
+            .render()
+    }
+}
+
+impl FromStr for Warehouse {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(Input::new(s))
+    }
+}
+
+impl Warehouse {
+    fn parse(s: Input) -> Result<Self, ParseError> {
+        let map: CharMap = s.grid(|tile| matches!(tile, '#' | '.' | '@' | 'O').then_some(tile))?;
+        if map.find(&'@').is_none() {
+            return Err(ParseError::MissingRobot);
+        }
+        Ok(Self::new(map))
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ParseError {
+    #[error(transparent)]
+    Parse(#[from] parse::ParseError),
+    #[error("The warehouse has no robot")]
+    MissingRobot,
+}
+
+fn parse_directions(s: Input) -> Result<Vec<Direction>, ParseError> {
+    s.lines()
+        .flat_map(|l| l.as_str().char_indices().map(move |(i, ch)| (l.span_at(i), ch)))
+        .map(|(span, ch)| match ch {
+            '^' => Ok(Direction::Up),
+            '>' => Ok(Direction::Right),
+            'v' => Ok(Direction::Down),
+            '<' => Ok(Direction::Left),
+            _ => Err(parse::ParseError::Unexpected { expected: "a movement".to_string(), found: ch.to_string(), span }.into()),
+        })
+        .collect()
+}
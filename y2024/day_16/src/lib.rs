@@ -0,0 +1,102 @@
+use std::{collections::HashSet, str::FromStr};
+use anyhow::Context;
+use serde::Deserialize;
+use xmas::{direction::{Direction, QuarterRotation}, map2d::CharMap, point2d::Point2D};
+use xmas::search::{Search, ShortestPaths};
+use xmas::solution::Solution;
+use xmas::visualize::Visualizer;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Parsed = Maze;
+    type Params = Params;
+    type Answer1 = u64;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Maze::from_str(input)
+    }
+
+    fn part_1(&self, maze: &Self::Parsed, _: &Params) -> anyhow::Result<u64> {
+        get_lowest_maze_cost(maze)
+    }
+
+    fn part_2(&self, maze: &Self::Parsed, params: &Params) -> anyhow::Result<usize> {
+        get_best_paths_tiles(maze, params.visualize)
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// Prints the maze with the tiles of the best paths colored
+    pub visualize: bool,
+}
+
+const STEP_COST: u64 = 1;
+const TURN_COST: u64 = 1000;
+
+#[derive(Debug, Clone)]
+pub struct Maze {
+    map: CharMap,
+    start: Point2D,
+    end: Point2D,
+}
+
+impl FromStr for Maze {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = CharMap::from_str(s)?;
+        let start = map.find(&'S').context("No start tile")?;
+        let end = map.find(&'E').context("No end tile")?;
+        Ok(Self { map, start, end })
+    }
+}
+
+pub fn get_lowest_maze_cost(maze: &Maze) -> anyhow::Result<u64> {
+    Ok(best_paths(maze)?.cost)
+}
+
+pub fn get_best_paths_tiles(maze: &Maze, visualize: bool) -> anyhow::Result<usize> {
+    let points: HashSet<Point2D> = best_paths(maze)?.states().into_iter().map(|(point, _)| point).collect();
+    if visualize {
+        Visualizer::new(&maze.map)
+            .path(points.iter().copied())
+            .highlight([maze.start, maze.end])
+            .print();
+    }
+    Ok(points.len())
+}
+
+fn best_paths(maze: &Maze) -> anyhow::Result<ShortestPaths<(Point2D, Direction)>> {
+    let search = Search::new(|&(pos, dir): &(Point2D, Direction)| {
+        let turned_cost = TURN_COST + STEP_COST;
+        [
+            (dir, STEP_COST),
+            (dir.turn(QuarterRotation::Right), turned_cost),
+            (dir.turn(QuarterRotation::Left), turned_cost),
+        ]
+            .into_iter()
+            .map(|(dir, cost)| ((pos + dir.as_point(), dir), cost))
+            .filter(|((pos, _), _)| maze.map.get_tile(*pos).is_some_and(|t| t != &'#'))
+            .collect()
+    });
+
+    search.all_shortest_paths((maze.start, Direction::Right), |&(pos, _)| pos == maze.end)
+        .context("No path from the start to the end")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fails_when_the_end_cant_be_reached() {
+        let maze = Puzzle.parse("#####\n#S#E#\n#####\n").unwrap();
+
+        assert!(Puzzle.part_1(&maze, &Params::default()).is_err());
+        assert!(Puzzle.part_2(&maze, &Params::default()).is_err());
+    }
+}
@@ -1,10 +1,30 @@
-use std::str::FromStr;
 use genawaiter::{rc::gen, yield_};
-use regex_static::{once_cell::sync::Lazy, Regex, lazy_regex};
+use thiserror::Error;
+use xmas::parse::{self, AocParse, FromInput, Input, Span};
+use xmas::solution::Solution;
 
-const DIGIT_REGEX: Lazy<Regex> = lazy_regex!(r"\d+");
+pub struct Puzzle;
 
-type TinyByte = u8;
+impl Solution for Puzzle {
+    type Parsed = (Computer, Vec<TinyByte>);
+    type Params = ();
+    type Answer1 = String;
+    type Answer2 = Register;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part_1(&self, (computer, program): &Self::Parsed, _: &()) -> anyhow::Result<String> {
+        Ok(format_output(&execute_all_instructions(computer, program)))
+    }
+
+    fn part_2(&self, (_, program): &Self::Parsed, _: &()) -> anyhow::Result<Register> {
+        Ok(calculate_required_a_value(program))
+    }
+}
+
+pub type TinyByte = u8;
 pub type Register = u64;
 
 pub const INS_ADV: TinyByte = 0;
@@ -16,26 +36,21 @@ pub const INS_OUT: TinyByte = 5;
 pub const INS_BDV: TinyByte = 6;
 pub const INS_CDV: TinyByte = 7;
 
-pub fn execute_all_instructions(input: &str) -> Vec<TinyByte> {
-    let (mut computer, program) = parse_input(input);
-    computer.execute(&program).collect()
+pub fn execute_all_instructions(computer: &Computer, program: &[TinyByte]) -> Vec<TinyByte> {
+    let mut computer = computer.clone();
+    computer.execute(program).collect()
 }
 
-pub fn calculate_required_a_value(input: &str) -> Register {
-    let (_, program) = parse_input(input);
-    println!("Target output = {:?}", program);
-
-    try_to_find_valid_a(0, &program).unwrap()
+pub fn calculate_required_a_value(program: &[TinyByte]) -> Register {
+    try_to_find_valid_a(0, program).unwrap()
 }
 
 // WON'T work with example input, use brute force from previous commit
 pub fn try_to_find_valid_a(a_subset: Register, program: &[TinyByte]) -> Option<Register> {
-    // println!("Testing: {}", a_subset);
     let subset_min = if a_subset == 0 { 1 } else { a_subset };
     for test_a in subset_min..(a_subset + 8) {
         let output: Vec<_> = Computer::with_a(test_a).execute(program).collect();
         let last_portion = &program[(program.len() - output.len())..];
-        // println!("{} Match: {:?} matches with last portion of {:?}", test_a, output, last_portion);
         if last_portion != output {
             continue;
         }
@@ -49,34 +64,45 @@ pub fn try_to_find_valid_a(a_subset: Register, program: &[TinyByte]) -> Option<R
     None
 }
 
-fn parse_input(input: &str) -> (Computer, Vec<TinyByte>) {
-    let (computer_s, program_s) = input.split_once("\n\n").unwrap();
-    let computer = Computer::from_str(computer_s).unwrap();
-    let program: Vec<TinyByte> = program_s.trim_start_matches("Program:")
-        .trim()
-        .split(',')
-        .map(|ins| ins.parse::<TinyByte>())
-        .collect::<Result<_, _>>()
-        .unwrap();
-
-    (computer, program)
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseError {
+    #[error(transparent)]
+    Parse(#[from] parse::ParseError),
+    #[error("Invalid instruction `{instruction}` @ {span}")]
+    InvalidInstruction { instruction: String, span: Span },
 }
 
-pub fn output_result(result: &[TinyByte]) {
-    let mut first = true;
-    for n in result {
-        if !first {
-            print!(",");
-        } else {
-            first = false;
-        }
-        print!("{n}");
-    }
-    println!();
+fn parse_input(input: &str) -> Result<(Computer, Vec<TinyByte>), ParseError> {
+    let [computer_s, program_s] = Input::new(input).sections_n()?;
+    let computer = Computer::from_input(computer_s)?;
+    let program = parse_program(program_s)?;
+
+    Ok((computer, program))
 }
 
-#[derive(Debug, Clone)]
-struct Computer {
+fn parse_program(s: Input) -> Result<Vec<TinyByte>, ParseError> {
+    let [list] = s.captures(&["Program: ", ""])?;
+    list.split(",")
+        .map(|ins| {
+            let ins = ins.trim();
+            ins.parse::<TinyByte>()
+                .ok()
+                .filter(|&ins| ins < 8)
+                .ok_or_else(|| ParseError::InvalidInstruction { instruction: ins.as_str().to_string(), span: ins.span() })
+        })
+        .collect()
+}
+
+pub fn format_output(result: &[TinyByte]) -> String {
+    result.iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[derive(Debug, Clone, AocParse)]
+#[aoc(fmt = "Register A: {register_a}\nRegister B: {register_b}\nRegister C: {register_c}")]
+pub struct Computer {
     register_a: Register,
     register_b: Register,
     register_c: Register,
@@ -107,9 +133,7 @@ impl Computer {
 
     fn dv_op(&self, operand: TinyByte) -> Register {
         let power = self.combo_value(operand) as u32;
-        let result = self.register_a.checked_shr(power).unwrap_or(0);
-        // println!("{} / 2 ^ {} = {}", self.register_a, power, result);
-        result
+        self.register_a.checked_shr(power).unwrap_or(0)
     }
 
     pub fn execute<'a>(&'a mut self, instructions: &'a [TinyByte]) -> impl Iterator<Item = TinyByte> + 'a {
@@ -132,7 +156,6 @@ impl Computer {
                     (INS_OUT, operand) => {
                         let val = self.combo_value(operand) as u8 % 8;
                         yield_!(val);
-                        // println!("out: {:?}", output);
                     },
                     (INS_BDV, operand) => self.register_b = self.dv_op(operand),
                     (INS_CDV, operand) => self.register_c = self.dv_op(operand),
@@ -145,19 +168,6 @@ impl Computer {
     }
 }
 
-impl FromStr for Computer {
-    type Err = anyhow::Error;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut lines = s.lines()
-            .map(|l| DIGIT_REGEX.find(l).unwrap().as_str().parse::<Register>().unwrap());
-        let a = lines.next().unwrap();
-        let b = lines.next().unwrap();
-        let c = lines.next().unwrap();
-        Ok(Self::new(a, b, c))
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -175,7 +185,7 @@ mod tests {
     fn example_2() {
         let mut computer = Computer::new(10, 0, 0);
 
-        let output: Vec<_> = computer.execute(&[5, 0, 1, 5, 4]).collect();
+        let output: Vec<_> = computer.execute(&[5, 0, 5, 1, 5, 4]).collect();
 
         assert_eq!(output, [0, 1, 2]);
     }
@@ -206,4 +216,22 @@ mod tests {
 
         assert_eq!(computer.register_b, 44354);
     }
+
+    #[test]
+    fn reports_where_the_input_is_malformed() {
+        let truncated = "Register A: 729\nRegister B: 0\n\nProgram: 0,1";
+        let bad_instruction = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,9";
+
+        assert_eq!(
+            parse_input(truncated).unwrap_err(),
+            ParseError::Parse(parse::ParseError::Missing {
+                expected: "`\\nRegister C: `".to_string(),
+                span: Span { line: 2, column: 14 },
+            }),
+        );
+        assert_eq!(
+            parse_input(bad_instruction).unwrap_err(),
+            ParseError::InvalidInstruction { instruction: "9".to_string(), span: Span { line: 5, column: 14 } },
+        );
+    }
 }
@@ -0,0 +1,119 @@
+use std::num::ParseIntError;
+use anyhow::Context;
+use xmas::{map2d::CharMap, point2d::Point2D, search::{Path, Search}};
+use serde::Deserialize;
+use thiserror::Error;
+use xmas::solution::Solution;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Parsed = Vec<Point2D>;
+    type Params = Params;
+    type Answer1 = u64;
+    type Answer2 = String;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        let bytes = input.lines()
+            .enumerate()
+            .map(|(i, l)| parse_point(l, i + 1))
+            .collect::<Result<_, _>>()?;
+        Ok(bytes)
+    }
+
+    fn part_1(&self, bytes: &Self::Parsed, params: &Params) -> anyhow::Result<u64> {
+        calculate_path_after_bytes(bytes, params.map_size, params.bytes)
+    }
+
+    fn part_2(&self, bytes: &Self::Parsed, params: &Params) -> anyhow::Result<String> {
+        let [x, y] = get_cutting_byte(bytes, params.map_size, params.bytes)?.0;
+        Ok(format!("{x},{y}"))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub map_size: Point2D,
+    /// Amount of bytes that have fallen before looking for a path
+    pub bytes: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { map_size: Point2D::new(71, 71), bytes: 1024 }
+    }
+}
+
+pub fn calculate_path_after_bytes(corrupt_at: &[Point2D], map_size: Point2D, bytes: usize) -> anyhow::Result<u64> {
+    let map = {
+        let mut map = CharMap::new_filled(map_size, '.');
+
+        for &point in corrupt_at.iter().take(bytes) {
+            map.set_tile(point, '#');
+        }
+        map
+    };
+
+    let Path { cost, .. } = try_get_path(&map)
+        .with_context(|| format!("No path to the exit after {bytes} bytes"))?;
+
+    Ok(cost)
+}
+
+pub fn get_cutting_byte(corrupt_at: &[Point2D], map_size: Point2D, skip: usize) -> anyhow::Result<Point2D> {
+    let mut map = CharMap::new_filled(map_size, '.');
+
+    let mut i = 0;
+    for &point in corrupt_at {
+        map.set_tile(point, '#');
+        i += 1;
+        if i <= skip {
+            continue;
+        }
+
+        let result = try_get_path(&map);
+        if result.is_none() {
+            return Ok(point);
+        }
+    }
+
+    anyhow::bail!("No byte cuts the path to the exit")
+}
+
+fn try_get_path(map: &CharMap) -> Option<Path<Point2D>> {
+    let target = map.size() - Point2D::new(1, 1);
+    Search::passable(map, |t| t == &'.')
+        .astar(Point2D::ZERO, |&p| p == target, |p| p.manhattan_distance(target) as u64)
+        .path
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ParseError {
+    #[error("Expected `x,y` @ {line}:1")]
+    MissingComma { line: usize },
+    #[error("Invalid coordinate `{coord}` @ {line}:{column}")]
+    InvalidCoordinate { coord: String, line: usize, column: usize, source: ParseIntError },
+}
+
+fn parse_point(s: &str, line: usize) -> Result<Point2D, ParseError> {
+    let (x, y) = s.split_once(',')
+        .ok_or(ParseError::MissingComma { line })?;
+    let parse_coord = |coord: &str, column| coord.parse()
+        .map_err(|source| ParseError::InvalidCoordinate { coord: coord.to_string(), line, column, source });
+    Ok(Point2D::new(parse_coord(x, 1)?, parse_coord(y, x.len() + 2)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fails_when_there_is_no_answer() {
+        let wall = [Point2D::new(1, 0), Point2D::new(1, 1)];
+        let size = Point2D::new(2, 2);
+
+        assert!(calculate_path_after_bytes(&wall, size, 2).is_err());
+        assert!(get_cutting_byte(&wall[..1], size, 0).is_err());
+    }
+}
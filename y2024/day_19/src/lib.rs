@@ -0,0 +1,94 @@
+use std::{cell::RefCell, collections::HashMap, str::FromStr};
+use xmas::parse::{Input, ParseError};
+use xmas::solution::Solution;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Parsed = Towels;
+    type Params = ();
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(Towels::from_str(input)?)
+    }
+
+    fn part_1(&self, towels: &Self::Parsed, _: &()) -> anyhow::Result<usize> {
+        Ok(get_possible_design_count(towels))
+    }
+
+    fn part_2(&self, towels: &Self::Parsed, _: &()) -> anyhow::Result<usize> {
+        Ok(get_total_amount_of_combinations(towels))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Towels {
+    patterns: Vec<String>,
+    designs: Vec<String>,
+}
+
+impl FromStr for Towels {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [patterns_s, designs_s] = Input::new(s).sections_n()?;
+        let patterns = patterns_s.comma_list()?;
+        let designs = designs_s.lines()
+            .map(|l| l.as_str().trim().to_string())
+            .collect();
+        Ok(Self { patterns, designs })
+    }
+}
+
+pub fn get_possible_design_count(towels: &Towels) -> usize {
+    let patterns = TowelPatterns::new(towels.patterns.clone());
+
+    towels.designs.iter()
+        .filter(|design| patterns.get_possible_designs(design) > 0)
+        .count()
+}
+
+pub fn get_total_amount_of_combinations(towels: &Towels) -> usize {
+    let patterns = TowelPatterns::new(towels.patterns.clone());
+
+    towels.designs.iter()
+        .map(|design| patterns.get_possible_designs(design))
+        .sum()
+}
+
+struct TowelPatterns<'a> {
+    patterns: Vec<String>,
+    cache: RefCell<HashMap<&'a str, usize>>,
+}
+
+impl<'a> TowelPatterns<'a> {
+    pub fn new(patterns: Vec<String>) -> Self {
+        Self { patterns, cache: Default::default() }
+    }
+
+    pub fn get_possible_designs(&self, design: &'a str) -> usize {
+        if let Some(&cached_result) = self.cache.borrow().get(design) {
+            return cached_result;
+        }
+
+        let mut possible_designs = 0;
+        for pattern in &self.patterns {
+            if !design.starts_with(pattern) {
+                continue;
+            }
+
+            if pattern.len() == design.len() {
+                possible_designs += 1;
+                continue;
+            }
+
+            let possible_rest_designs = self.get_possible_designs(&design[pattern.len()..]);
+            possible_designs += possible_rest_designs;
+        }
+        self.cache.borrow_mut().insert(design, possible_designs);
+        possible_designs
+    }
+}
+
@@ -0,0 +1,156 @@
+use std::{collections::{HashMap, HashSet}, str::FromStr};
+use anyhow::Context;
+use xmas::{direction::DIRECTIONS, map2d::CharMap, point2d::Point2D, search::Search};
+use serde::Deserialize;
+use xmas::parse::{Input, ParseError};
+use xmas::solution::Solution;
+use xmas::visualize::Visualizer;
+
+pub struct Puzzle;
+
+impl Solution for Puzzle {
+    type Parsed = Racetrack;
+    type Params = Params;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Parsed> {
+        Ok(Racetrack::from_str(input)?)
+    }
+
+    fn part_1(&self, track: &Self::Parsed, params: &Params) -> anyhow::Result<usize> {
+        calculate_best_shortcuts(track, params.required_saving, 2, params.debug)
+    }
+
+    fn part_2(&self, track: &Self::Parsed, params: &Params) -> anyhow::Result<usize> {
+        calculate_best_shortcuts(track, params.required_saving, 20, params.debug)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// Minimum picoseconds a cheat has to save to be counted
+    pub required_saving: u64,
+    /// Prints how many cheats save each amount of time, and the track with the
+    /// tiles where they start highlighted
+    pub debug: bool,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Self { required_saving: 100, debug: false }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Racetrack {
+    map: CharMap,
+    start: Point2D,
+    end: Point2D,
+}
+
+impl FromStr for Racetrack {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let input = Input::new(s);
+        let map = input.grid(|tile| matches!(tile, '.' | '#' | 'S' | 'E').then_some(tile))?;
+        let find = |tile: char| map.find(&tile)
+            .ok_or_else(|| ParseError::Missing { expected: format!("`{tile}` tile"), span: input.end() });
+        let start = find('S')?;
+        let end = find('E')?;
+        Ok(Self { map, start, end })
+    }
+}
+
+pub fn calculate_best_shortcuts(track: &Racetrack, shortcut_required_saving: u64, cheat_time: u64, debug: bool) -> anyhow::Result<usize> {
+    let Racetrack { map, start, end } = track;
+
+    let path = Search::passable(map, |t| t != &'#')
+        .bfs(*start, |p| p == end)
+        .path
+        .context("No path from the start to the end")?;
+
+    // Every step takes a picosecond, so the time a tile is reached is its index in the path
+    let time_per_tile: HashMap<Point2D, u64> = path.states.iter()
+        .enumerate()
+        .map(|(time, &point)| (point, time as u64))
+        .collect();
+
+    let mut cheats = vec![];
+    let mut cheat_starts = HashSet::new();
+    for (cur_time, &from) in path.states.iter().enumerate() {
+        let cur_time = cur_time as u64;
+
+        let mut points_to_check = vec![from];
+        let mut checked_points = HashSet::new();
+        while let Some(next_candidate) = points_to_check.pop() {
+            if checked_points.contains(&next_candidate) {
+                continue;
+            }
+            checked_points.insert(next_candidate);
+            let distance = next_candidate.manhattan_distance(from) as u64;
+            if distance > cheat_time {
+                continue;
+            }
+
+            points_to_check.extend(DIRECTIONS.iter()
+                .map(|dir| next_candidate + dir.as_point())
+                .filter(|p| !checked_points.contains(p)));
+
+            let time_with_cheat = cur_time + distance;
+            let other_time = match time_per_tile.get(&next_candidate) {
+                Some(time) => *time,
+                None => continue,
+            };
+
+            if time_with_cheat >= other_time {
+                continue;
+            }
+
+            let save_time = other_time - time_with_cheat;
+            if save_time >= shortcut_required_saving {
+                cheats.push(save_time);
+                cheat_starts.insert(from);
+            }
+        }
+    }
+
+    let mut cheat_count = HashMap::new();
+    for cheat in cheats {
+        *cheat_count.entry(cheat).or_insert(0) += 1;
+    }
+
+    if debug {
+        println!("Base path calculated with cost: {}", path.cost);
+        let mut cheat_count_vec: Vec<_> = cheat_count.iter().collect();
+        cheat_count_vec.sort_by_key(|kvp| kvp.0);
+        for (save_time, count) in cheat_count_vec {
+            println!("- There are {count} cheat/s that save {save_time} picoseconds.");
+        }
+
+        Visualizer::new(map)
+            .path(path.states.iter().copied())
+            .highlight(cheat_starts)
+            .print();
+    }
+
+    Ok(cheat_count.values().sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use xmas::parse::Span;
+
+    use super::*;
+
+    #[test]
+    fn requires_a_start_and_an_end() {
+        assert_eq!(
+            Racetrack::from_str("#####\n#S..#\n#####\n").unwrap_err(),
+            ParseError::Missing { expected: "`E` tile".to_string(), span: Span { line: 4, column: 1 } },
+        );
+        assert_eq!(Racetrack::from_str("#S.E#\n#.x.#").unwrap_err().span(), Span { line: 2, column: 3 });
+    }
+}